@@ -2,26 +2,552 @@
 
 multiversx_sc::imports!();
 
+// Se incrementeaza la fiecare schimbare a structurii ProofData
+pub const PROOF_SCHEMA_VERSION: u32 = 16;
+
+// Limitele globale implicite pentru proof_text necomprimat
+pub const DEFAULT_MIN_PROOF_TEXT_LEN: u32 = 1;
+pub const DEFAULT_MAX_PROOF_TEXT_LEN: u32 = 500;
+
+// Numarul maxim de zecimale acceptat pentru scoruri
+pub const MAX_SCORE_DECIMALS: u8 = 18;
+
+// Dimensiunea maxima a campului metadata
+pub const MAX_METADATA_LEN: usize = 1024;
+
+// Dimensiunea maxima a titlului afisat, separat de proof_text
+pub const MAX_TITLE_LEN: usize = 100;
+
+// Dimensiunea maxima a tag-ului de regiune (ex. "EU", "US")
+pub const MAX_REGION_LEN: usize = 16;
+
+// Limita pentru payload-uri comprimate (contractul nu decomprima)
+pub const MAX_COMPRESSED_TEXT_LEN: usize = 4096;
+
+// Dimensiunea clasamentului celor mai atestate dovezi
+pub const MAX_TOP_PROOFS: usize = 20;
+
+// Lungimea (in caractere hex) a id-urilor sugerate
+pub const SUGGESTED_ID_LEN: usize = 32;
+
+// Id-urile generate de certifyAuto: hex(adresa | nonce bloc | contor), 48 de octeti -> 96 de caractere
+pub const AUTO_ID_RAW_LEN: usize = 48;
+
+// 10000 bps = 100%
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+// Numarul maxim de beneficiari intre care se impart taxele
+pub const MAX_FEE_BENEFICIARIES: usize = 10;
+
+// Endpoint-ul apelat pe contractul listener si gas-ul alocat apelului
+pub const PROOF_LISTENER_ENDPOINT: &str = "onProofCertified";
+pub const LISTENER_CALL_GAS: u64 = 5_000_000;
+
+// Numarul maxim de reguli prefix -> categorie si de prefixe rezervate (limiteaza gas-ul la certificare)
+pub const MAX_PREFIX_RULES: usize = 50;
+
+// Provocarea de ownership semnata off-chain de owner-ul dovezii
+pub const OWNERSHIP_CHALLENGE_PREFIX: &[u8] = b"mvx-onchain-proof:ownership:";
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+pub const RELAY_MESSAGE_PREFIX: &[u8] = b"mvx-onchain-proof:relay:";
+
+// Limitele listei de subsiruri interzise (costul scanarii la fiecare certificare)
+pub const MAX_BANNED_SUBSTRINGS: usize = 20;
+pub const MAX_BANNED_SUBSTRING_LEN: usize = 64;
+
+// Cate dovezi ale unui utilizator sunt sortate de getUserProofTimeline
+pub const MAX_TIMELINE_SCAN: usize = 100;
+
+// Cate dovezi ale unui utilizator sunt insumate de getTotalDocumentBytes
+pub const MAX_DOCUMENT_SIZE_SCAN: usize = 100;
+
+// Bitii din ProofData::flags
+pub const FLAG_REVOKED: u16 = 1 << 0;
+pub const FLAG_FROZEN: u16 = 1 << 1;
+pub const FLAG_IMMUTABLE: u16 = 1 << 2;
+pub const FLAG_ENCRYPTED: u16 = 1 << 3;
+pub const FLAG_PRIVATE: u16 = 1 << 4;
+pub const FLAGS_LAYOUT_VERSION: u16 = 1;
+
 #[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
 pub struct ProofData<M: ManagedTypeApi> {
     pub proof_text: ManagedBuffer<M>,
     pub timestamp: u64,
     pub proof_id: ManagedBuffer<M>,
     pub metadata: ManagedBuffer<M>,
+    pub encryption_scheme: ManagedBuffer<M>,
+    pub issuer: ManagedAddress<M>,
+    pub superseded_by: ManagedBuffer<M>,
+    pub fee_paid: BigUint<M>,
+    pub content_hash: ManagedBuffer<M>,
+    pub category: ManagedBuffer<M>,
+    pub expires_at: u64,
+    pub score_value: u64,
+    pub score_decimals: u8,
+    pub compressed: bool,
+    pub compression_algo: ManagedBuffer<M>,
+    // Bitii 0-7: revoked, frozen, immutable, encrypted, private; bitii 8-15: versiunea layout-ului
+    pub flags: u16,
+    pub fee_token: EgldOrEsdtTokenIdentifier<M>,
+    pub epoch: u64,
+    // Reputatia emitentului in momentul certificarii
+    pub issuer_reputation_at_issue: u64,
+    pub region: ManagedBuffer<M>,
+    // Dovada este valida doar de la acest timestamp (0 = imediat)
+    pub valid_from: u64,
+    // Dimensiunea in bytes a documentului off-chain (0 = necunoscuta)
+    pub document_size: u64,
+    // Alternativa la expires_at: dovada expira la aceasta epoca (0 = fara expirare pe epoci)
+    pub valid_until_epoch: u64,
+    // Titlu scurt pentru afisare (gol = fara titlu)
+    pub title: ManagedBuffer<M>,
+    // sha256 peste restul campurilor, recalculat la fiecare salvare
+    pub checksum: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> ProofData<M> {
+    fn has_flag(&self, mask: u16) -> bool {
+        self.flags & mask != 0
+    }
+    
+    fn set_flag(&mut self, mask: u16, enabled: bool) {
+        if enabled {
+            self.flags |= mask;
+        } else {
+            self.flags &= !mask;
+        }
+    }
+    
+    pub fn is_revoked(&self) -> bool {
+        self.has_flag(FLAG_REVOKED)
+    }
+    
+    pub fn set_revoked(&mut self, revoked: bool) {
+        self.set_flag(FLAG_REVOKED, revoked);
+    }
+    
+    pub fn is_frozen(&self) -> bool {
+        self.has_flag(FLAG_FROZEN)
+    }
+    
+    pub fn set_frozen(&mut self, frozen: bool) {
+        self.set_flag(FLAG_FROZEN, frozen);
+    }
+    
+    pub fn is_immutable(&self) -> bool {
+        self.has_flag(FLAG_IMMUTABLE)
+    }
+    
+    pub fn set_immutable(&mut self, immutable: bool) {
+        self.set_flag(FLAG_IMMUTABLE, immutable);
+    }
+    
+    pub fn is_encrypted(&self) -> bool {
+        self.has_flag(FLAG_ENCRYPTED)
+    }
+    
+    pub fn set_encrypted(&mut self, encrypted: bool) {
+        self.set_flag(FLAG_ENCRYPTED, encrypted);
+    }
+    
+    pub fn is_private(&self) -> bool {
+        self.has_flag(FLAG_PRIVATE)
+    }
+    
+    pub fn set_private(&mut self, private: bool) {
+        self.set_flag(FLAG_PRIVATE, private);
+    }
+    
+    pub fn flags_version(&self) -> u8 {
+        (self.flags >> 8) as u8
+    }
+    
+    /// Forma decodificata expusa de view-uri, cu flag-urile ca booleeni separati
+    pub fn into_view(self) -> ProofView<M> {
+        ProofView {
+            encrypted: self.is_encrypted(),
+            revoked: self.is_revoked(),
+            frozen: self.is_frozen(),
+            immutable: self.is_immutable(),
+            private: self.is_private(),
+            proof_text: self.proof_text,
+            timestamp: self.timestamp,
+            proof_id: self.proof_id,
+            metadata: self.metadata,
+            encryption_scheme: self.encryption_scheme,
+            issuer: self.issuer,
+            superseded_by: self.superseded_by,
+            fee_paid: self.fee_paid,
+            content_hash: self.content_hash,
+            category: self.category,
+            expires_at: self.expires_at,
+            score_value: self.score_value,
+            score_decimals: self.score_decimals,
+            compressed: self.compressed,
+            compression_algo: self.compression_algo,
+            fee_token: self.fee_token,
+            epoch: self.epoch,
+            issuer_reputation_at_issue: self.issuer_reputation_at_issue,
+            region: self.region,
+            valid_from: self.valid_from,
+            document_size: self.document_size,
+            valid_until_epoch: self.valid_until_epoch,
+            title: self.title,
+        }
+    }
+}
+
+// Layout-ul ProofData din prima versiune a contractului; citit doar de migrateProofs
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
+pub struct LegacyProofData<M: ManagedTypeApi> {
+    pub proof_text: ManagedBuffer<M>,
+    pub timestamp: u64,
+    pub proof_id: ManagedBuffer<M>,
+    pub metadata: ManagedBuffer<M>,
+}
+
+// Layout-ul extern al unei dovezi, neschimbat fata de versiunea cu booleeni separati
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone)]
+pub struct ProofView<M: ManagedTypeApi> {
+    pub proof_text: ManagedBuffer<M>,
+    pub timestamp: u64,
+    pub proof_id: ManagedBuffer<M>,
+    pub metadata: ManagedBuffer<M>,
+    pub encrypted: bool,
+    pub encryption_scheme: ManagedBuffer<M>,
+    pub issuer: ManagedAddress<M>,
+    pub revoked: bool,
+    pub superseded_by: ManagedBuffer<M>,
+    pub fee_paid: BigUint<M>,
+    pub content_hash: ManagedBuffer<M>,
+    pub category: ManagedBuffer<M>,
+    pub expires_at: u64,
+    pub score_value: u64,
+    pub score_decimals: u8,
+    pub frozen: bool,
+    pub compressed: bool,
+    pub compression_algo: ManagedBuffer<M>,
+    pub immutable: bool,
+    pub fee_token: EgldOrEsdtTokenIdentifier<M>,
+    pub epoch: u64,
+    pub issuer_reputation_at_issue: u64,
+    pub private: bool,
+    pub region: ManagedBuffer<M>,
+    pub valid_from: u64,
+    pub document_size: u64,
+    pub valid_until_epoch: u64,
+    pub title: ManagedBuffer<M>,
+}
+
+// Optiunile certificarii; un camp gol / 0 / None pastreaza comportamentul implicit
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone)]
+pub struct CertifyOptions<M: ManagedTypeApi> {
+    pub metadata: ManagedBuffer<M>,
+    pub encryption_scheme: ManagedBuffer<M>,
+    pub content_hash: ManagedBuffer<M>,
+    // Gol = categoria derivata din prefixul proof_id
+    pub category: ManagedBuffer<M>,
+    // None = TTL-ul implicit, Some(0) = dovada nu expira
+    pub validity_seconds: Option<u64>,
+    // Scor fractionar: 3.75 = (375, 2)
+    pub score_value: u64,
+    pub score_decimals: u8,
+    pub compression_algo: ManagedBuffer<M>,
+    pub immutable: bool,
+    pub referrer: Option<ManagedAddress<M>>,
+    pub region: ManagedBuffer<M>,
+    pub valid_from: u64,
+    pub gating_nonce: u64,
+    pub document_size: u64,
+    // Alternativa la validity_seconds (0 = fara expirare pe epoci)
+    pub valid_until_epoch: u64,
+    pub title: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> Default for CertifyOptions<M> {
+    fn default() -> Self {
+        CertifyOptions {
+            metadata: ManagedBuffer::new(),
+            encryption_scheme: ManagedBuffer::new(),
+            content_hash: ManagedBuffer::new(),
+            category: ManagedBuffer::new(),
+            validity_seconds: None,
+            score_value: 0,
+            score_decimals: 0,
+            compression_algo: ManagedBuffer::new(),
+            immutable: false,
+            referrer: None,
+            region: ManagedBuffer::new(),
+            valid_from: 0,
+            gating_nonce: 0,
+            document_size: 0,
+            valid_until_epoch: 0,
+            title: ManagedBuffer::new(),
+        }
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
+pub struct PendingProof<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub proof_text: ManagedBuffer<M>,
+    pub metadata: ManagedBuffer<M>,
+    pub required_sigs: u32,
+    // Taxa achitata la propunere, contabilizata la atingerea cvorumului
+    pub fee_paid: BigUint<M>,
+    pub fee_token: EgldOrEsdtTokenIdentifier<M>,
+    // NFT-ul de acces indicat de propunator, reverificat la finalizare
+    pub gating_nonce: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
+pub struct ProofCollection<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub name: ManagedBuffer<M>,
+    pub metadata: ManagedBuffer<M>,
+    pub created_at: u64,
+}
+
+// Payload-ul evenimentului proofUpdatedDetailed
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
+pub struct ProofTextDiff<M: ManagedTypeApi> {
+    pub old_text: ManagedBuffer<M>,
+    pub new_text: ManagedBuffer<M>,
+}
+
+// Forma compacta pastrata dupa arhivarea unei dovezi vechi
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
+pub struct ArchivedProof<M: ManagedTypeApi> {
+    pub proof_id: ManagedBuffer<M>,
+    pub owner: ManagedAddress<M>,
+    pub timestamp: u64,
+    pub content_hash: ManagedBuffer<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone)]
+pub struct ProofFlag<M: ManagedTypeApi> {
+    pub flagger: ManagedAddress<M>,
+    pub reason: ManagedBuffer<M>,
+    pub timestamp: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone)]
+pub struct RankedProof<M: ManagedTypeApi> {
+    pub proof_id: ManagedBuffer<M>,
+    pub attestations: u32,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone)]
+pub struct FeeBeneficiary<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub bps: u32,
+}
+
+// Starea unica raportata clientilor; Sealed = dovada marcata imutabila, Escrowed = asteapta releaseProof,
+// NotYetValid = valid_from inca nu a fost atins
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone, Copy)]
+pub enum ProofStatus {
+    Active,
+    Revoked,
+    Frozen,
+    Expired,
+    Sealed,
+    Escrowed,
+    NotYetValid,
 }
 
 #[multiversx_sc::contract]
 pub trait OnChainProof {
     #[init]
-    fn init(&self) {}
+    fn init(&self) {
+        self.schema_version().set(PROOF_SCHEMA_VERSION);
+    }
 
+    /// `expected_version` trebuie sa fie versiunea codului nou si strict mai mare
+    /// decat cea stocata, ca un redeploy accidental al unui build vechi sa esueze.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, expected_version: u32) {
+        require!(
+            self.blockchain().get_caller() == self.blockchain().get_owner_address(),
+            "Only owner can upgrade"
+        );
+        require!(expected_version == PROOF_SCHEMA_VERSION, "Schema version mismatch");
+        require!(
+            expected_version > self.schema_version().get(),
+            "Downgrade not allowed"
+        );
+        
+        self.schema_version().set(expected_version);
+    }
+
+    // Blocheaza mutatiile utilizatorilor
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    // Motivul pauzei, public pentru transparenta; se sterge la reluare
+    #[storage_mapper("pauseReason")]
+    fn pause_reason(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    // Pur informativ: clientii afiseaza un banner de mentenanta
+    #[storage_mapper("maintenanceMode")]
+    fn maintenance_mode(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setPaused)]
+    fn set_paused(&self, paused: bool, reason: OptionalValue<ManagedBuffer>) {
+        self.paused().set(paused);
+        
+        match reason.into_option() {
+            Some(reason) if paused => self.pause_reason().set(reason),
+            _ => self.pause_reason().clear(),
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setMaintenanceMode)]
+    fn set_maintenance_mode(&self, enabled: bool) {
+        self.maintenance_mode().set(enabled);
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+    }
+
+    // TTL aplicat cand certify_action nu primeste validity_seconds (0 = nu expira)
+    #[storage_mapper("defaultValidity")]
+    fn default_validity(&self) -> SingleValueMapper<u64>;
+
+    #[only_owner]
+    #[endpoint(setDefaultValidity)]
+    fn set_default_validity(&self, seconds: u64) {
+        self.default_validity().set(seconds);
+    }
+
+    #[view(getDefaultValidity)]
+    fn get_default_validity(&self) -> u64 {
+        self.default_validity().get()
+    }
+
+    // Cand este activ, certify_action accepta doar apeluri venite de la contracte (middleware)
+    #[storage_mapper("requireContractCaller")]
+    fn require_contract_caller(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setRequireContractCaller)]
+    fn set_require_contract_caller(&self, enabled: bool) {
+        self.require_contract_caller().set(enabled);
+    }
+
+    // Cand este activ, update_proof emite si textul anterior (cost de gas mai mare)
+    #[storage_mapper("emitUpdateDiffs")]
+    fn emit_update_diffs(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setEmitUpdateDiffs)]
+    fn set_emit_update_diffs(&self, enabled: bool) {
+        self.emit_update_diffs().set(enabled);
+    }
+
+    // Versiunea schemei de evenimente, emisa ca primul topic indexat al fiecarui eveniment
+    #[storage_mapper("eventSchemaVersion")]
+    fn event_schema_version(&self) -> SingleValueMapper<u32>;
+
+    #[only_owner]
+    #[endpoint(setEventSchemaVersion)]
+    fn set_event_schema_version(&self, version: u32) {
+        self.event_schema_version().set(version);
+    }
+
+    #[view(getEventSchemaVersion)]
+    fn event_version(&self) -> u32 {
+        self.event_schema_version().get()
+    }
+
+    // Spre deosebire de pauza, sigilarea este ireversibila
+    #[storage_mapper("sealed")]
+    fn sealed(&self) -> SingleValueMapper<bool>;
+
+    /// Opreste definitiv emiterea de dovezi noi; verificarea si revocarea raman active.
+    #[only_owner]
+    #[endpoint(sealContract)]
+    fn seal_contract(&self) {
+        require!(!self.sealed().get(), "Contract is already sealed");
+        self.sealed().set(true);
+        self.contract_sealed_event(self.event_version(), self.blockchain().get_block_timestamp());
+    }
+
+    fn require_not_sealed(&self) {
+        require!(!self.sealed().get(), "Contract is sealed");
+    }
+
+    // Cand este activ, dovezile nu mai pot fi modificate; certificarea si revocarea raman active
+    #[storage_mapper("appendOnly")]
+    fn append_only(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setAppendOnly)]
+    fn set_append_only(&self, enabled: bool) {
+        self.append_only().set(enabled);
+    }
+
+    #[view(isAppendOnly)]
+    fn is_append_only(&self) -> bool {
+        self.append_only().get()
+    }
+
+    fn require_not_append_only(&self) {
+        require!(!self.append_only().get(), "Contract is append-only");
+    }
+
+    // Importul din backup poate fi blocat definitiv dupa migrare
+    #[storage_mapper("importLocked")]
+    fn import_locked(&self) -> SingleValueMapper<bool>;
+
+    /// Restaureaza o dovada exportata, pastrand timestamp-ul original.
+    /// Actualizeaza indexurile si contoarele ca la o certificare, fara taxe sau listener.
+    #[only_owner]
+    #[endpoint(importProof)]
+    fn import_proof(&self, owner: ManagedAddress, proof_data: ProofData<Self::Api>) {
+        require!(!self.import_locked().get(), "Import is locked");
+        require!(
+            self.proof_owners(&proof_data.proof_id).is_empty()
+                && self.pending_proofs(&proof_data.proof_id).is_empty()
+                && self.archived_proofs(&proof_data.proof_id).is_empty(),
+            "Proof ID already exists"
+        );
+        
+        if !proof_data.content_hash.is_empty() && self.proof_by_content_hash(&proof_data.content_hash).is_empty() {
+            self.proof_by_content_hash(&proof_data.content_hash).set(&proof_data.proof_id);
+        }
+        
+        let proof_id = proof_data.proof_id.clone();
+        self.store_new_proof(&owner, proof_data);
+        self.proof_imported_event(self.event_version(), &owner, &proof_id);
+    }
+
+    #[only_owner]
+    #[endpoint(lockImport)]
+    fn lock_import(&self) {
+        self.import_locked().set(true);
+    }
+
+    #[view(isImportLocked)]
+    fn is_import_locked(&self) -> bool {
+        self.import_locked().get()
+    }
+
+    // Versiunea schemei ProofData folosita de contract
+    #[storage_mapper("schemaVersion")]
+    fn schema_version(&self) -> SingleValueMapper<u32>;
 
     // Storage pentru dovezi multiple per utilizator
     #[storage_mapper("userProofs")]
     fn user_proofs(&self, user: &ManagedAddress, proof_id: &ManagedBuffer) -> SingleValueMapper<ProofData<Self::Api>>;
 
+    // Aceeasi cheie, octetii bruti: permite detectarea inregistrarilor cu layout vechi
+    #[storage_mapper("userProofs")]
+    fn user_proofs_raw(&self, user: &ManagedAddress, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
     // Lista cu toate proof_id-urile unui utilizator
     #[storage_mapper("userProofIds")]
     fn user_proof_ids(&self, user: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
@@ -38,144 +564,3815 @@ pub trait OnChainProof {
     #[storage_mapper("proofOwners")]
     fn proof_owners(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
 
-    #[endpoint]
-    fn certify_action(&self, proof_text: ManagedBuffer, proof_id: ManagedBuffer, metadata: OptionalValue<ManagedBuffer>) {
-        let caller = self.blockchain().get_caller();
-        let current_timestamp = self.blockchain().get_block_timestamp();
+    // Index global al dovezilor existente (pentru snapshot-uri paginate)
+    #[storage_mapper("allProofIds")]
+    fn all_proof_ids(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // Toti emitentii care au certificat dovezi si cate dovezi existente a emis fiecare
+    #[storage_mapper("knownIssuers")]
+    fn known_issuers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("issuerProofCount")]
+    fn issuer_proof_count(&self, issuer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Toti utilizatorii care au detinut dovezi (pentru recalcularea contoarelor)
+    #[storage_mapper("proofHolders")]
+    fn proof_holders(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Acumulator pentru recompute_total paginat
+    #[storage_mapper("recomputeTotalAcc")]
+    fn recompute_total_acc(&self) -> SingleValueMapper<u64>;
+
+    // Dovezi salvate de fiecare utilizator (nu necesita ownership)
+    #[storage_mapper("userBookmarks")]
+    fn user_bookmarks(&self, user: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // Colectii de dovezi (ex: un curs cu mai multe module)
+    #[storage_mapper("collections")]
+    fn collections(&self, collection_id: &ManagedBuffer) -> SingleValueMapper<ProofCollection<Self::Api>>;
+
+    #[storage_mapper("collectionProofs")]
+    fn collection_proofs(&self, collection_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // O dovada apartine cel mult unei colectii
+    #[storage_mapper("proofCollection")]
+    fn proof_collection(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    // Adrese care pot edita textul/metadatele unei dovezi fara a o detine
+    #[storage_mapper("proofEditors")]
+    fn proof_editors(&self, proof_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Co-owneri adaugati de owner-ul principal; pot edita ca editorii, dar nu pot transfera
+    #[storage_mapper("proofCoOwners")]
+    fn proof_co_owners(&self, proof_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Adrese care pot citi o dovada privata fara a o detine
+    #[storage_mapper("proofReaders")]
+    fn proof_readers(&self, proof_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Dovezile noi sunt private implicit; cele existente nu se schimba
+    #[storage_mapper("defaultPrivate")]
+    fn default_private(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setDefaultPrivate)]
+    fn set_default_private(&self, enabled: bool) {
+        self.default_private().set(enabled);
+    }
+
+    // De cate ori a fost inregistrata vizualizarea unei dovezi
+    #[storage_mapper("proofViewCount")]
+    fn proof_view_count(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    // Adresele care au atestat o dovada
+    #[storage_mapper("proofAttesters")]
+    fn proof_attesters(&self, proof_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    // sha256 al conditiei care elibereaza o dovada din escrow (gol = dovada nu este in escrow)
+    #[storage_mapper("escrowConditionHash")]
+    fn escrow_condition_hash(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    // Dovada canonica in care a fost contopit un duplicat (gol = nu a fost contopita)
+    #[storage_mapper("mergedInto")]
+    fn merged_into(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    // Cate dovezi trimit spre aceasta prin superseded_by sau mergedInto (blocheaza redenumirea)
+    #[storage_mapper("inboundLinkCount")]
+    fn inbound_link_count(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    // Timestamp-ul de expirare al fiecarei atestari (gol = nu expira)
+    #[storage_mapper("attestationExpiry")]
+    fn attestation_expiry(&self, proof_id: &ManagedBuffer, attester: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Index invers atestator -> dovezile atestate
+    #[storage_mapper("attesterProofs")]
+    fn attester_proofs(&self, attester: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // Top dovezi dupa numarul de atestari, sortat descrescator
+    #[storage_mapper("topProofs")]
+    fn top_proofs(&self) -> VecMapper<RankedProof<Self::Api>>;
+
+    // Numarul minim de atestari pentru ca o dovada sa fie considerata activa (0 = dezactivat)
+    #[storage_mapper("attestationThreshold")]
+    fn attestation_threshold(&self) -> SingleValueMapper<u32>;
+
+    #[only_owner]
+    #[endpoint(setAttestationThreshold)]
+    fn set_attestation_threshold(&self, k: u32) {
+        self.attestation_threshold().set(k);
+    }
+
+    // Ponderea atestarilor unui atestator (gol = pondere 1)
+    #[storage_mapper("attesterWeight")]
+    fn attester_weight(&self, attester: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Scorul ponderat minim pentru ca o dovada sa fie activa (0 = dezactivat)
+    #[storage_mapper("weightedAttestationThreshold")]
+    fn weighted_attestation_threshold(&self) -> SingleValueMapper<u64>;
+
+    #[only_owner]
+    #[endpoint(setAttesterWeight)]
+    fn set_attester_weight(&self, attester: ManagedAddress, weight: u64) {
+        self.attester_weight(&attester).set(weight);
+    }
+
+    #[only_owner]
+    #[endpoint(setWeightedAttestationThreshold)]
+    fn set_weighted_attestation_threshold(&self, threshold: u64) {
+        self.weighted_attestation_threshold().set(threshold);
+    }
+
+    // Destinatarul unei oferte de transfer in asteptare
+    #[storage_mapper("pendingTransfer")]
+    fn pending_transfer(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    // Secventa intra-bloc pentru ordonarea totala a dovezilor din acelasi bloc
+    #[storage_mapper("sequenceBlockNonce")]
+    fn sequence_block_nonce(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("blockSequence")]
+    fn block_sequence(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("proofSequence")]
+    fn proof_sequence(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    // Legaturi parinte -> copii (o dovada are cel mult un parinte)
+    #[storage_mapper("proofParent")]
+    fn proof_parent(&self, child_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("proofChildren")]
+    fn proof_children(&self, parent_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // Setat pe copii cand parintele este revocat si propagarea este activa
+    #[storage_mapper("parentRevoked")]
+    fn parent_revoked(&self, child_id: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("propagateParentRevocation")]
+    fn propagate_parent_revocation(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setPropagateParentRevocation)]
+    fn set_propagate_parent_revocation(&self, enabled: bool) {
+        self.propagate_parent_revocation().set(enabled);
+    }
+
+    // Semnalari in asteptarea revizuirii de catre owner-ul contractului
+    #[storage_mapper("proofFlags")]
+    fn proof_flags(&self, proof_id: &ManagedBuffer) -> VecMapper<ProofFlag<Self::Api>>;
+
+    #[storage_mapper("proofFlaggers")]
+    fn proof_flaggers(&self, proof_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("proofFlagCount")]
+    fn proof_flag_count(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    /// Daca semnalarea este confirmata dovada este revocata; in ambele cazuri semnalarile se sterg.
+    #[only_owner]
+    #[endpoint(resolveFlag)]
+    fn resolve_flag(&self, proof_id: ManagedBuffer, uphold: bool) {
+        require!(self.proof_flag_count(&proof_id).get() > 0, "Proof has no flags");
         
-        // Verifică dacă proof_id este unic
-        require!(
-            self.proof_owners(&proof_id).is_empty(),
-            "Proof ID already exists"
-        );
+        if uphold {
+            let owner = self.proof_owners(&proof_id).get();
+            let mut proof_data = self.user_proofs(&owner, &proof_id).get();
+            if !proof_data.is_revoked() {
+                proof_data.set_revoked(true);
+                self.save_proof(&owner, &proof_id, proof_data);
+                
+                self.proof_revoked_event(self.event_version(), &self.blockchain().get_caller(), &proof_id);
+                self.flag_children_of_revoked(&proof_id);
+            }
+        }
         
-        // Verifică lungimea proof_text
-        require!(
-            proof_text.len() > 0 && proof_text.len() <= 500,
-            "Proof text must be between 1 and 500 characters"
-        );
+        self.clear_proof_flags(&proof_id);
+        self.flag_resolved_event(self.event_version(), &proof_id, uphold);
+    }
+
+    // Dovezi arhivate: doar id, owner, timestamp si hash
+    #[storage_mapper("archivedProofs")]
+    fn archived_proofs(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ArchivedProof<Self::Api>>;
+
+    // Varsta minima (secunde) de la care o dovada poate fi arhivata (0 = dezactivat)
+    #[storage_mapper("retentionPeriod")]
+    fn retention_period(&self) -> SingleValueMapper<u64>;
+
+    #[only_owner]
+    #[endpoint(setRetentionPeriod)]
+    fn set_retention_period(&self, seconds: u64) {
+        self.retention_period().set(seconds);
+    }
+
+    /// Muta dovezile mai vechi decat perioada de retentie in forma compacta
+    /// si elibereaza inregistrarea completa.
+    #[only_owner]
+    #[endpoint(archiveOldProofs)]
+    fn archive_old_proofs(&self, proof_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        let retention = self.retention_period().get();
+        require!(retention > 0, "Archiving is disabled");
         
-        let metadata_buffer = match metadata {
-            OptionalValue::Some(meta) => meta,
-            OptionalValue::None => ManagedBuffer::new(),
-        };
+        let now = self.blockchain().get_block_timestamp();
+        let mut archived = 0u32;
         
-        let proof_data = ProofData {
-            proof_text: proof_text.clone(),
-            timestamp: current_timestamp,
-            proof_id: proof_id.clone(),
-            metadata: metadata_buffer,
-        };
+        for proof_id in proof_ids {
+            let proof_data = self.load_proof(&proof_id);
+            require!(
+                now.saturating_sub(proof_data.timestamp) >= retention,
+                "Proof is too recent to archive"
+            );
+            
+            let owner = self.proof_owners(&proof_id).get();
+            self.remove_proof_storage(&owner, &proof_id);
+            self.archived_proofs(&proof_id).set(ArchivedProof {
+                proof_id: proof_id.clone(),
+                owner,
+                timestamp: proof_data.timestamp,
+                content_hash: proof_data.content_hash,
+            });
+            
+            self.proof_archived_event(self.event_version(), &proof_id);
+            archived += 1;
+        }
         
-        // Salvează dovada
-        self.user_proofs(&caller, &proof_id).set(proof_data);
+        archived
+    }
+
+    // Nonce-ul inclus in urmatorul mesaj de relay semnat de utilizator
+    #[storage_mapper("relayNonce")]
+    fn relay_nonce(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Programe de referral: cine a adus fiecare dovada si cate a adus fiecare adresa
+    #[storage_mapper("proofReferrer")]
+    fn proof_referrer(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("referralCount")]
+    fn referral_count(&self, referrer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Emitenti autorizati sa aprobe dovezi cu cvorum
+    #[storage_mapper("authorizedIssuers")]
+    fn authorized_issuers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Dovezi propuse care asteapta aprobari
+    #[storage_mapper("pendingProofs")]
+    fn pending_proofs(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<PendingProof<Self::Api>>;
+
+    // Emitentii care au aprobat o dovada propusa
+    #[storage_mapper("proofApprovals")]
+    fn proof_approvals(&self, proof_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Contor pentru proof_id-urile generate automat per utilizator
+    #[storage_mapper("autoProofNonce")]
+    fn auto_proof_nonce(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Total taxe platite la certificare
+    #[storage_mapper("totalFeesCollected")]
+    fn total_fees_collected(&self) -> SingleValueMapper<BigUint>;
+
+    // Index invers content_hash -> proof_id (prima dovada care a certificat documentul)
+    #[storage_mapper("proofByContentHash")]
+    fn proof_by_content_hash(&self, content_hash: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    // Respinge certificarea aceluiasi content_hash de doua ori
+    #[storage_mapper("enforceUniqueContentHash")]
+    fn enforce_unique_content_hash(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setEnforceUniqueContentHash)]
+    fn set_enforce_unique_content_hash(&self, enabled: bool) {
+        self.enforce_unique_content_hash().set(enabled);
+    }
+
+    // Categorii permise (administrate de owner)
+    #[storage_mapper("allowedCategories")]
+    fn allowed_categories(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("enforceCategoryAllowlist")]
+    fn enforce_category_allowlist(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setEnforceCategoryAllowlist)]
+    fn set_enforce_category_allowlist(&self, enabled: bool) {
+        self.enforce_category_allowlist().set(enabled);
+    }
+
+    #[only_owner]
+    #[endpoint(addAllowedCategory)]
+    fn add_allowed_category(&self, category: ManagedBuffer) {
+        require!(!category.is_empty(), "Category cannot be empty");
+        self.allowed_categories().insert(category);
+    }
+
+    #[only_owner]
+    #[endpoint(removeAllowedCategory)]
+    fn remove_allowed_category(&self, category: ManagedBuffer) {
+        self.allowed_categories().swap_remove(&category);
+    }
+
+    // Index invers regiune -> proof_id-uri; lungimea setului este contorul regiunii
+    #[storage_mapper("proofsByRegion")]
+    fn proofs_by_region(&self, region: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // Subsiruri interzise in proof_text (lista mica, scanata la fiecare certificare)
+    #[storage_mapper("bannedSubstrings")]
+    fn banned_substrings(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[only_owner]
+    #[endpoint(addBannedSubstring)]
+    fn add_banned_substring(&self, substring: ManagedBuffer) {
+        require!(
+            !substring.is_empty() && substring.len() <= MAX_BANNED_SUBSTRING_LEN,
+            "Invalid banned substring length"
+        );
+        require!(
+            self.banned_substrings().len() < MAX_BANNED_SUBSTRINGS,
+            "Too many banned substrings"
+        );
+        self.banned_substrings().insert(substring);
+    }
+
+    #[only_owner]
+    #[endpoint(removeBannedSubstring)]
+    fn remove_banned_substring(&self, substring: ManagedBuffer) {
+        self.banned_substrings().swap_remove(&substring);
+    }
+
+    // Numarul de dovezi existente per categorie
+    #[storage_mapper("categoryCount")]
+    fn category_count(&self, category: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    // Numarul de dovezi detinute de fiecare utilizator per categorie
+    #[storage_mapper("userCategoryCount")]
+    fn user_category_count(
+        &self,
+        user: &ManagedAddress,
+        category: &ManagedBuffer,
+    ) -> SingleValueMapper<u64>;
+
+    // Tag-ul de schema cu care trebuie sa inceapa metadata (gol = fara validare)
+    #[storage_mapper("requiredMetadataPrefix")]
+    fn required_metadata_prefix(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[only_owner]
+    #[endpoint(setRequiredMetadataPrefix)]
+    fn set_required_metadata_prefix(&self, prefix: ManagedBuffer) {
+        self.required_metadata_prefix().set(prefix);
+    }
+
+    // Reguli prefix proof_id -> categorie, folosite cand categoria lipseste
+    #[storage_mapper("prefixCategoryRules")]
+    fn prefix_category_rules(&self) -> MapMapper<ManagedBuffer, ManagedBuffer>;
+
+    #[only_owner]
+    #[endpoint(setPrefixCategory)]
+    fn set_prefix_category(&self, prefix: ManagedBuffer, category: ManagedBuffer) {
+        require!(!prefix.is_empty(), "Prefix cannot be empty");
+        require!(!category.is_empty(), "Category cannot be empty");
+        
+        let mut rules = self.prefix_category_rules();
+        require!(
+            rules.contains_key(&prefix) || rules.len() < MAX_PREFIX_RULES,
+            "Too many prefix rules"
+        );
+        rules.insert(prefix, category);
+    }
+
+    #[only_owner]
+    #[endpoint(removePrefixCategory)]
+    fn remove_prefix_category(&self, prefix: ManagedBuffer) {
+        self.prefix_category_rules().remove(&prefix);
+    }
+
+    // Prefixe de proof_id rezervate pentru un singur emitent
+    #[storage_mapper("reservedPrefixes")]
+    fn reserved_prefixes(&self) -> MapMapper<ManagedBuffer, ManagedAddress>;
+
+    #[only_owner]
+    #[endpoint(reservePrefix)]
+    fn reserve_prefix(&self, prefix: ManagedBuffer, issuer: ManagedAddress) {
+        require!(!prefix.is_empty(), "Prefix cannot be empty");
+        
+        let mut reserved = self.reserved_prefixes();
+        require!(
+            reserved.contains_key(&prefix) || reserved.len() < MAX_PREFIX_RULES,
+            "Too many reserved prefixes"
+        );
+        reserved.insert(prefix, issuer);
+    }
+
+    #[only_owner]
+    #[endpoint(releasePrefix)]
+    fn release_prefix(&self, prefix: ManagedBuffer) {
+        self.reserved_prefixes().remove(&prefix);
+    }
+
+    fn is_prefix_reserved_for_other(&self, proof_id: &ManagedBuffer, issuer: &ManagedAddress) -> bool {
+        for (prefix, owner) in self.reserved_prefixes().iter() {
+            if &owner == issuer || prefix.len() > proof_id.len() {
+                continue;
+            }
+            if proof_id.copy_slice(0, prefix.len()) == Some(prefix) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Pauza minima intre doua certificari ale aceluiasi apelant (0 = dezactivat)
+    #[storage_mapper("certifyCooldown")]
+    fn certify_cooldown(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("lastCertifyTimestamp")]
+    fn last_certify_timestamp(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[only_owner]
+    #[endpoint(setCertifyCooldown)]
+    fn set_certify_cooldown(&self, seconds: u64) {
+        self.certify_cooldown().set(seconds);
+    }
+
+    // Cat timp un proof_id sters nu poate fi refolosit (0 = dezactivat)
+    #[storage_mapper("idReuseCooldown")]
+    fn id_reuse_cooldown(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("deletedProofTimestamp")]
+    fn deleted_proof_timestamp(&self, proof_id: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[only_owner]
+    #[endpoint(setIdReuseCooldown)]
+    fn set_id_reuse_cooldown(&self, seconds: u64) {
+        self.id_reuse_cooldown().set(seconds);
+    }
+
+    // Limitare certificari per apelant per fereastra de timp (0 = dezactivat)
+    #[storage_mapper("rateLimitMax")]
+    fn rate_limit_max(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rateLimitWindow")]
+    fn rate_limit_window(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rateWindowStart")]
+    fn rate_window_start(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rateWindowCount")]
+    fn rate_window_count(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[only_owner]
+    #[endpoint(setRateLimit)]
+    fn set_rate_limit(&self, max_per_window: u64, window_seconds: u64) {
+        require!(
+            max_per_window == 0 || window_seconds > 0,
+            "Window must be greater than zero"
+        );
+        self.rate_limit_max().set(max_per_window);
+        self.rate_limit_window().set(window_seconds);
+    }
+
+    // Taxele disponibile in contract (spre deosebire de totalFeesCollected, scade la plati)
+    #[storage_mapper("feeReserve")]
+    fn fee_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    // Limitele globale pentru proof_text; valorile implicite se aplica cat timp sunt goale
+    #[storage_mapper("minProofTextLen")]
+    fn min_proof_text_len(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("maxProofTextLen")]
+    fn max_proof_text_len(&self) -> SingleValueMapper<u32>;
+
+    #[only_owner]
+    #[endpoint(setMinProofTextLen)]
+    fn set_min_proof_text_len(&self, min: u32) {
+        let (_, max) = self.global_text_bounds();
+        require!(min > 0, "Minimum length must be at least 1");
+        require!(min <= max, "Minimum length cannot exceed maximum");
+        self.min_proof_text_len().set(min);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxProofTextLen)]
+    fn set_max_proof_text_len(&self, max: u32) {
+        let (min, _) = self.global_text_bounds();
+        require!(max >= min, "Maximum length cannot be below minimum");
+        require!(max as usize <= MAX_COMPRESSED_TEXT_LEN, "Text limit too large");
+        self.max_proof_text_len().set(max);
+    }
+
+    fn global_text_bounds(&self) -> (u32, u32) {
+        let min = if self.min_proof_text_len().is_empty() {
+            DEFAULT_MIN_PROOF_TEXT_LEN
+        } else {
+            self.min_proof_text_len().get()
+        };
+        let max = if self.max_proof_text_len().is_empty() {
+            DEFAULT_MAX_PROOF_TEXT_LEN
+        } else {
+            self.max_proof_text_len().get()
+        };
+        (min, max)
+    }
+
+    // Limite (min, max) pentru proof_text specifice unui emitent
+    #[storage_mapper("issuerTextLimit")]
+    fn issuer_text_limit(&self, issuer: &ManagedAddress) -> SingleValueMapper<(u32, u32)>;
+
+    #[only_owner]
+    #[endpoint(setIssuerTextLimit)]
+    fn set_issuer_text_limit(&self, issuer: ManagedAddress, min: u32, max: u32) {
+        require!(min > 0 && min <= max, "Invalid text limits");
+        require!(max as usize <= MAX_COMPRESSED_TEXT_LEN, "Text limit too large");
+        self.issuer_text_limit(&issuer).set((min, max));
+    }
+
+    #[only_owner]
+    #[endpoint(clearIssuerTextLimit)]
+    fn clear_issuer_text_limit(&self, issuer: ManagedAddress) {
+        self.issuer_text_limit(&issuer).clear();
+    }
+
+    // Rata fiecarui token de taxa catre unitatea contabila (0 = token neacceptat)
+    #[storage_mapper("feeTokenRate")]
+    fn fee_token_rate(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // Toate taxele incasate, convertite in unitatea contabila la rata din momentul platii
+    #[storage_mapper("totalFeesInUnit")]
+    fn total_fees_in_unit(&self) -> SingleValueMapper<BigUint>;
+
+    /// EGLD este acceptat mereu; fara rata setata platile in EGLD nu contribuie la totalFeesInUnit.
+    #[only_owner]
+    #[endpoint(setFeeTokenRate)]
+    fn set_fee_token_rate(&self, token: EgldOrEsdtTokenIdentifier, rate_to_unit: BigUint) {
+        require!(token.is_valid(), "Invalid token identifier");
+        self.fee_token_rate(&token).set(rate_to_unit);
+    }
+
+    // Echivalentul feeReserve pentru taxele platite in ESDT
+    #[storage_mapper("esdtFeeReserve")]
+    fn esdt_fee_reserve(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    fn tracked_fee_balance(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint> {
+        if token.is_egld() {
+            self.fee_reserve()
+        } else {
+            self.esdt_fee_reserve(&token.clone().unwrap_esdt())
+        }
+    }
+
+    // Recompensa ESDT platita emitentului pentru fiecare dovada, din pool-ul alimentat separat
+    #[storage_mapper("rewardToken")]
+    fn reward_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("rewardPerProof")]
+    fn reward_per_proof(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("rewardPool")]
+    fn reward_pool(&self) -> SingleValueMapper<BigUint>;
+
+    #[only_owner]
+    #[endpoint(setRewardConfig)]
+    fn set_reward_config(&self, token: TokenIdentifier, amount_per_proof: BigUint) {
+        require!(token.is_valid_esdt_identifier(), "Invalid token identifier");
+        require!(
+            self.reward_token().is_empty() || self.reward_token().get() == token || self.reward_pool().get() == 0,
+            "Reward pool must be empty to change token"
+        );
+        
+        self.reward_token().set(token);
+        self.reward_per_proof().set(amount_per_proof);
+    }
+
+    #[payable("*")]
+    #[endpoint(fundRewardPool)]
+    fn fund_reward_pool(&self) {
+        self.require_not_paused();
+        require!(!self.reward_token().is_empty(), "Reward not configured");
+        
+        let (token, amount) = self.call_value().single_fungible_esdt();
+        require!(token == self.reward_token().get(), "Wrong reward token");
+        self.reward_pool().update(|pool| *pool += amount);
+    }
+
+    // Platita emitentului pe orice cale de certificare; pool-ul gol sau insuficient nu blocheaza certificarea
+    fn pay_certification_reward(&self, issuer: &ManagedAddress) {
+        if self.reward_token().is_empty() {
+            return;
+        }
+        
+        let amount = self.reward_per_proof().get();
+        let pool = self.reward_pool().get();
+        if amount == 0 || pool < amount {
+            return;
+        }
+        
+        self.reward_pool().set(pool - &amount);
+        self.send().direct_esdt(issuer, &self.reward_token().get(), 0, &amount);
+    }
+
+    // Impartirea taxelor colectate (bps, suma 10000); gol = retragere manuala cu withdrawFeesTo
+    #[storage_mapper("feeBeneficiaries")]
+    fn fee_beneficiaries(&self) -> VecMapper<FeeBeneficiary<Self::Api>>;
+
+    #[only_owner]
+    #[endpoint(setFeeBeneficiaries)]
+    fn set_fee_beneficiaries(&self, beneficiaries: MultiValueEncoded<MultiValue2<ManagedAddress, u32>>) {
+        let mut stored = self.fee_beneficiaries();
+        stored.clear();
+        
+        let mut total_bps = 0u32;
+        for beneficiary in beneficiaries {
+            let (address, bps) = beneficiary.into_tuple();
+            require!(bps > 0, "Beneficiary share must be positive");
+            require!(bps <= BPS_DENOMINATOR, "Beneficiary share exceeds 10000 bps");
+            require!(stored.len() < MAX_FEE_BENEFICIARIES, "Too many fee beneficiaries");
+            // Release-ul ruleaza fara overflow-checks, deci suma nu trebuie sa poata face wrap
+            total_bps = match total_bps.checked_add(bps) {
+                Some(total) => total,
+                None => sc_panic!("Beneficiary shares must sum to 10000 bps"),
+            };
+            stored.push(&FeeBeneficiary { address, bps });
+        }
+        
+        require!(
+            stored.is_empty() || total_bps == BPS_DENOMINATOR,
+            "Beneficiary shares must sum to 10000 bps"
+        );
+    }
+
+    /// Imparte intreaga rezerva de taxe a token-ului intre beneficiari, proportional cu bps.
+    /// Restul din rotunjire merge la ultimul beneficiar.
+    #[only_owner]
+    #[endpoint(distributeFees)]
+    fn distribute_fees(&self, token: EgldOrEsdtTokenIdentifier) {
+        let beneficiaries = self.fee_beneficiaries();
+        require!(!beneficiaries.is_empty(), "No fee beneficiaries configured");
+        
+        let total = self.tracked_fee_balance(&token).take();
+        require!(total > 0, "No fees to distribute");
+        
+        let mut remaining = total.clone();
+        let count = beneficiaries.len();
+        for index in 1..=count {
+            let beneficiary = beneficiaries.get(index);
+            let share = if index == count {
+                remaining.clone()
+            } else {
+                &total * &BigUint::from(beneficiary.bps) / BigUint::from(BPS_DENOMINATOR)
+            };
+            remaining -= &share;
+            
+            if share > 0 {
+                self.send().direct(&beneficiary.address, &token, 0, &share);
+                self.fees_withdrawn_event(self.event_version(), &beneficiary.address, &token, &share);
+            }
+        }
+    }
+
+    /// Trimite taxe colectate catre orice adresa (ex. multisig-ul trezoreriei)
+    #[only_owner]
+    #[endpoint(withdrawFeesTo)]
+    fn withdraw_fees_to(&self, to: ManagedAddress, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        require!(amount > 0, "Amount must be greater than zero");
+        require!(
+            self.fee_beneficiaries().is_empty(),
+            "Fees are split between beneficiaries"
+        );
+        
+        let balance = self.tracked_fee_balance(&token);
+        require!(amount <= balance.get(), "Amount exceeds fee balance");
+        balance.update(|reserve| *reserve -= &amount);
+        
+        self.send().direct(&to, &token, 0, &amount);
+        self.fees_withdrawn_event(self.event_version(), &to, &token, &amount);
+    }
+
+    // Recompensa per dovada expirata stearsa prin sweep_expired
+    #[storage_mapper("sweepBounty")]
+    fn sweep_bounty(&self) -> SingleValueMapper<BigUint>;
+
+    #[only_owner]
+    #[endpoint(setSweepBounty)]
+    fn set_sweep_bounty(&self, bounty: BigUint) {
+        self.sweep_bounty().set(bounty);
+    }
+
+    // Restrictioneaza proof_id la caractere URL-safe
+    #[storage_mapper("enforceSafeIds")]
+    fn enforce_safe_ids(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setEnforceSafeIds)]
+    fn set_enforce_safe_ids(&self, enabled: bool) {
+        self.enforce_safe_ids().set(enabled);
+    }
+
+    // Taxa de certificare in EGLD (0 = certificare gratuita)
+    #[storage_mapper("certificationFee")]
+    fn certification_fee(&self) -> SingleValueMapper<BigUint>;
+
+    // Taxa EGLD pentru transfer_proof_ownership (0 = transfer gratuit)
+    #[storage_mapper("transferFee")]
+    fn transfer_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[only_owner]
+    #[endpoint(setTransferFee)]
+    fn set_transfer_fee(&self, fee: BigUint) {
+        self.transfer_fee().set(fee);
+    }
+
+    // Reputatia emitentului = atestari primite pe dovezile emise de el
+    #[storage_mapper("issuerReputation")]
+    fn issuer_reputation(&self, issuer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("reputationDiscountMin")]
+    fn reputation_discount_min(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("reputationDiscountBps")]
+    fn reputation_discount_bps(&self) -> SingleValueMapper<u32>;
+
+    #[only_owner]
+    #[endpoint(setCertificationFee)]
+    fn set_certification_fee(&self, fee: BigUint) {
+        self.certification_fee().set(fee);
+    }
+
+    // Taxa de certificare specifica unei categorii (gol = se aplica taxa globala)
+    #[storage_mapper("categoryFee")]
+    fn category_fee(&self, category: &ManagedBuffer) -> SingleValueMapper<BigUint>;
+
+    /// O taxa 0 sterge suprascrierea si categoria revine la taxa globala.
+    #[only_owner]
+    #[endpoint(setCategoryFee)]
+    fn set_category_fee(&self, category: ManagedBuffer, fee: BigUint) {
+        require!(!category.is_empty(), "Category cannot be empty");
+        self.category_fee(&category).set(fee);
+    }
+
+    /// Taxa de baza pentru categorie, inainte de reducerea de reputatie
+    #[view(getCategoryFee)]
+    fn get_category_fee(&self, category: &ManagedBuffer) -> BigUint {
+        if category.is_empty() || self.category_fee(category).is_empty() {
+            self.certification_fee().get()
+        } else {
+            self.category_fee(category).get()
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setReputationFeeDiscount)]
+    fn set_reputation_fee_discount(&self, min_reputation: u64, discount_bps: u32) {
+        self.reputation_discount_min().set(min_reputation);
+        self.reputation_discount_bps().set(core::cmp::min(discount_bps, BPS_DENOMINATOR));
+    }
+
+    // Colectia NFT pe care trebuie sa o detina certificatorii (gol = fara gating)
+    #[storage_mapper("gatingNft")]
+    fn gating_nft(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[only_owner]
+    #[endpoint(setGatingNft)]
+    fn set_gating_nft(&self, token: TokenIdentifier) {
+        require!(token.is_valid_esdt_identifier(), "Invalid token identifier");
+        self.gating_nft().set(token);
+    }
+
+    #[only_owner]
+    #[endpoint(clearGatingNft)]
+    fn clear_gating_nft(&self) {
+        self.gating_nft().clear();
+    }
+
+    /// Soldul se verifica per nonce, asa ca apelantul indica NFT-ul detinut din colectie.
+    fn holds_gating_nft(&self, holder: &ManagedAddress, gating_nonce: u64) -> bool {
+        if self.gating_nft().is_empty() {
+            return true;
+        }
+        
+        gating_nonce > 0 && self.blockchain().get_esdt_balance(holder, &self.gating_nft().get(), gating_nonce) > 0
+    }
+
+    // Categoria (ex. "identity") in care certificatorul trebuie sa detina o dovada valida (gol = dezactivat)
+    #[storage_mapper("requiredPrerequisiteCategory")]
+    fn required_prerequisite_category(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[only_owner]
+    #[endpoint(setRequiredPrerequisiteCategory)]
+    fn set_required_prerequisite_category(&self, category: ManagedBuffer) {
+        self.required_prerequisite_category().set(category);
+    }
+
+    #[view(getRequiredPrerequisiteCategory)]
+    fn get_required_prerequisite_category(&self) -> ManagedBuffer {
+        self.required_prerequisite_category().get()
+    }
+
+    /// Contorul per categorie evita scanarea cand utilizatorul nu are nicio dovada in categorie.
+    fn has_prerequisite_proof(&self, holder: &ManagedAddress) -> bool {
+        let category = self.required_prerequisite_category().get();
+        if category.is_empty() {
+            return true;
+        }
+        
+        if self.user_category_count(holder, &category).get() == 0 {
+            return false;
+        }
+        for proof_id in self.user_proof_ids(holder).iter() {
+            let proof_data = self.user_proofs(holder, &proof_id).get();
+            if proof_data.category == category && self.is_proof_valid(&proof_id) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Contract notificat la fiecare dovada noua
+    #[storage_mapper("proofListener")]
+    fn proof_listener(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[only_owner]
+    #[endpoint(setProofListener)]
+    fn set_proof_listener(&self, sc_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&sc_address),
+            "Listener must be a smart contract"
+        );
+        self.proof_listener().set(sc_address);
+    }
+
+    #[only_owner]
+    #[endpoint(clearProofListener)]
+    fn clear_proof_listener(&self) {
+        self.proof_listener().clear();
+    }
+
+    // Sold minim EGLD cerut pentru certificare (0 = dezactivat)
+    #[storage_mapper("minCertifierBalance")]
+    fn min_certifier_balance(&self) -> SingleValueMapper<BigUint>;
+
+    #[only_owner]
+    #[endpoint(setMinCertifierBalance)]
+    fn set_min_certifier_balance(&self, min: BigUint) {
+        self.min_certifier_balance().set(min);
+    }
+
+    // Cand este activ, doar emitentii autorizati (si owner-ul contractului) pot certifica
+    #[storage_mapper("issuerWhitelistEnabled")]
+    fn issuer_whitelist_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[only_owner]
+    #[endpoint(setIssuerWhitelistEnabled)]
+    fn set_issuer_whitelist_enabled(&self, enabled: bool) {
+        self.issuer_whitelist_enabled().set(enabled);
+    }
+
+    // Owner-ul are bypass implicit ca certificarile operationale sa nu fie blocate
+    fn is_whitelisted_issuer(&self, address: &ManagedAddress) -> bool {
+        !self.issuer_whitelist_enabled().get()
+            || address == &self.blockchain().get_owner_address()
+            || self.authorized_issuers().contains(address)
+    }
+
+    // Regulile de acces ale emitentului, comune tuturor cailor de certificare si getEffectiveIssuerPermission.
+    // Restrictia la contracte priveste apelantul tranzactiei (ex. middleware-ul de relay), restul emitentul.
+    fn certify_denial_reason(
+        &self,
+        caller: &ManagedAddress,
+        issuer: &ManagedAddress,
+        category: &ManagedBuffer,
+        gating_nonce: u64,
+    ) -> Option<&'static str> {
+        if self.require_contract_caller().get() && !self.blockchain().is_smart_contract(caller) {
+            return Some("Only contract callers allowed");
+        }
+        if !self.is_whitelisted_issuer(issuer) {
+            return Some("Issuer not whitelisted");
+        }
+        if !self.holds_gating_nft(issuer, gating_nonce) {
+            return Some("Gating NFT required");
+        }
+        // Dovezile din categoria ceruta sunt scutite, altfel nimeni nu ar putea obtine prima dovada
+        if category != &self.required_prerequisite_category().get() && !self.has_prerequisite_proof(issuer) {
+            return Some("Prerequisite proof required");
+        }
+        
+        // Anti-sybil: certificatorul trebuie sa detina un sold minim
+        let min_balance = self.min_certifier_balance().get();
+        if min_balance > 0 && self.blockchain().get_balance(issuer) < min_balance {
+            return Some("Insufficient balance to certify");
+        }
+        
+        None
+    }
+    
+    fn require_can_certify(&self, caller: &ManagedAddress, issuer: &ManagedAddress, category: &ManagedBuffer, gating_nonce: u64) {
+        if let Some(reason) = self.certify_denial_reason(caller, issuer, category, gating_nonce) {
+            sc_panic!(reason);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(addAuthorizedIssuer)]
+    fn add_authorized_issuer(&self, issuer: ManagedAddress) {
+        self.authorized_issuers().insert(issuer);
+    }
+
+    #[only_owner]
+    #[endpoint(removeAuthorizedIssuer)]
+    fn remove_authorized_issuer(&self, issuer: ManagedAddress) {
+        self.authorized_issuers().swap_remove(&issuer);
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn certify_action(&self, proof_text: ManagedBuffer, proof_id: ManagedBuffer, metadata: OptionalValue<ManagedBuffer>) {
+        let mut options = CertifyOptions::default();
+        if let OptionalValue::Some(meta) = metadata {
+            options.metadata = meta;
+        }
+        
+        self.certify_with_options(proof_text, proof_id, options);
+    }
+    
+    /// Certificare cu toate optiunile intr-o singura structura; clientii completeaza
+    /// doar campurile de care au nevoie.
+    #[payable("*")]
+    #[endpoint(certifyWithOptions)]
+    fn certify_with_options(&self, proof_text: ManagedBuffer, proof_id: ManagedBuffer, options: CertifyOptions<Self::Api>) {
+        let caller = self.blockchain().get_caller();
+        
+        let mut proof_data = self.new_proof_data(&caller, proof_id, proof_text, options.metadata);
+        
+        // Contractul nu decripteaza - doar semnalizeaza clientilor schema folosita
+        proof_data.set_encrypted(!options.encryption_scheme.is_empty());
+        proof_data.encryption_scheme = options.encryption_scheme;
+        proof_data.content_hash = options.content_hash;
+        
+        proof_data.category = options.category;
+        
+        // Expirarea se da fie in secunde, fie ca epoca finala, niciodata ambele
+        if options.valid_until_epoch > 0 {
+            require!(options.validity_seconds.is_none(), "Validity can be set in seconds or epochs, not both");
+            require!(options.valid_until_epoch > proof_data.epoch, "Expiry epoch must be in the future");
+            proof_data.valid_until_epoch = options.valid_until_epoch;
+        } else {
+            // 0 = dovada nu expira; fara valoare explicita se aplica TTL-ul implicit
+            let validity = options.validity_seconds.unwrap_or_else(|| self.default_validity().get());
+            if validity > 0 {
+                // Un wrap ar stoca dovada deja expirata, stearsa apoi de sweep_expired
+                proof_data.expires_at = match proof_data.timestamp.checked_add(validity) {
+                    Some(expires_at) => expires_at,
+                    None => sc_panic!("Proof validity too long"),
+                };
+            }
+        }
+        
+        require!(options.score_decimals <= MAX_SCORE_DECIMALS, "Too many score decimals");
+        proof_data.score_value = options.score_value;
+        proof_data.score_decimals = options.score_decimals;
+        
+        // Payload comprimat de client - contractul doar il marcheaza
+        proof_data.compressed = !options.compression_algo.is_empty();
+        proof_data.compression_algo = options.compression_algo;
+        
+        // Odata setat, flag-ul nu mai poate fi anulat
+        proof_data.set_immutable(options.immutable);
+        
+        require!(options.region.len() <= MAX_REGION_LEN, "Region too long");
+        proof_data.region = options.region;
+        proof_data.valid_from = options.valid_from;
+        proof_data.document_size = options.document_size;
+        
+        self.require_valid_title(&options.title);
+        proof_data.title = options.title;
+        
+        let proof_id = proof_data.proof_id.clone();
+        self.certify_internal(&caller, proof_data, options.gating_nonce);
+        
+        if let Some(referrer_address) = options.referrer {
+            require!(referrer_address != caller, "Self-referral not allowed");
+            self.referral_count(&referrer_address).update(|count| *count += 1);
+            self.proof_referrer(&proof_id).set(referrer_address);
+        }
+    }
+    
+    #[payable("*")]
+    #[endpoint]
+    fn certify_auto(
+        &self,
+        proof_text: ManagedBuffer,
+        metadata: OptionalValue<ManagedBuffer>,
+        gating_nonce: OptionalValue<u64>,
+    ) -> ManagedBuffer {
+        let caller = self.blockchain().get_caller();
+        
+        // proof_id = hex(adresa apelantului | nonce bloc | contor per utilizator)
+        let auto_nonce = self.auto_proof_nonce(&caller).get();
+        self.auto_proof_nonce(&caller).set(auto_nonce + 1);
+        
+        let mut raw_id = [0u8; AUTO_ID_RAW_LEN];
+        raw_id[..32].copy_from_slice(&caller.to_byte_array());
+        raw_id[32..40].copy_from_slice(&self.blockchain().get_block_nonce().to_be_bytes());
+        raw_id[40..].copy_from_slice(&auto_nonce.to_be_bytes());
+        let mut encoded = [0u8; AUTO_ID_RAW_LEN * 2];
+        self.encode_hex(&raw_id, &mut encoded);
+        let proof_id = ManagedBuffer::new_from_bytes(&encoded);
+        
+        let metadata_buffer = match metadata {
+            OptionalValue::Some(meta) => meta,
+            OptionalValue::None => ManagedBuffer::new(),
+        };
+        
+        let proof_data = self.new_proof_data(&caller, proof_id.clone(), proof_text, metadata_buffer);
+        self.certify_internal(&caller, proof_data, gating_nonce.into_option().unwrap_or_default());
+        self.proof_id_generated_event(self.event_version(), &caller, &proof_id);
+        
+        proof_id
+    }
+    
+    /// Certifica o dovada in escrow: ramane inactiva pana cand cineva prezinta preimaginea
+    /// lui `release_condition_hash` prin `release_proof` (ex. la atingerea unui milestone).
+    #[payable("*")]
+    #[endpoint(escrowProof)]
+    fn escrow_proof(
+        &self,
+        proof_text: ManagedBuffer,
+        proof_id: ManagedBuffer,
+        metadata: ManagedBuffer,
+        release_condition_hash: ManagedBuffer,
+        gating_nonce: OptionalValue<u64>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(release_condition_hash.len() == 32, "Invalid condition hash");
+        
+        let proof_data = self.new_proof_data(&caller, proof_id.clone(), proof_text, metadata);
+        self.certify_internal(&caller, proof_data, gating_nonce.into_option().unwrap_or_default());
+        self.escrow_condition_hash(&proof_id).set(release_condition_hash);
+        
+        self.proof_escrowed_event(self.event_version(), &caller, &proof_id);
+    }
+    
+    /// Oricine cunoaste preimaginea conditiei poate activa dovada.
+    #[endpoint(releaseProof)]
+    fn release_proof(&self, proof_id: ManagedBuffer, condition_preimage: ManagedBuffer) {
+        self.require_not_paused();
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(!self.escrow_condition_hash(&proof_id).is_empty(), "Proof is not in escrow");
+        
+        let preimage_hash = self.crypto().sha256(&condition_preimage);
+        require!(
+            preimage_hash.as_managed_buffer() == &self.escrow_condition_hash(&proof_id).get(),
+            "Release condition not met"
+        );
+        
+        self.escrow_condition_hash(&proof_id).clear();
+        self.proof_released_event(self.event_version(), &self.blockchain().get_caller(), &proof_id);
+    }
+    
+    /// Certificare platita de un relayer in numele lui `user`. Utilizatorul semneaza
+    /// mesajul intors de getRelayMessage, care include nonce-ul sau curent.
+    #[payable("EGLD")]
+    #[endpoint(relayCertify)]
+    fn relay_certify(
+        &self,
+        user: ManagedAddress,
+        proof_text: ManagedBuffer,
+        proof_id: ManagedBuffer,
+        metadata: ManagedBuffer,
+        user_signature: ManagedBuffer,
+        gating_nonce: OptionalValue<u64>,
+    ) {
+        // Nonce-ul semnat se incrementeaza, deci o semnatura nu mai verifica a doua oara
+        let message = self.get_relay_message(&user, &proof_id, &proof_text, &metadata);
+        self.crypto().verify_ed25519(user.as_managed_buffer(), &message, &user_signature);
+        
+        self.relay_nonce(&user).update(|nonce| *nonce += 1);
+        
+        let proof_data = self.new_proof_data(&user, proof_id, proof_text, metadata);
+        self.certify_internal(&user, proof_data, gating_nonce.into_option().unwrap_or_default());
+    }
+    
+    fn new_proof_data(&self, issuer: &ManagedAddress, proof_id: ManagedBuffer, proof_text: ManagedBuffer, metadata: ManagedBuffer) -> ProofData<Self::Api> {
+        let mut flags = FLAGS_LAYOUT_VERSION << 8;
+        if self.default_private().get() {
+            flags |= FLAG_PRIVATE;
+        }
+        
+        ProofData {
+            proof_text,
+            timestamp: self.blockchain().get_block_timestamp(),
+            proof_id,
+            metadata,
+            encryption_scheme: ManagedBuffer::new(),
+            issuer: issuer.clone(),
+            superseded_by: ManagedBuffer::new(),
+            fee_paid: BigUint::zero(),
+            content_hash: ManagedBuffer::new(),
+            category: ManagedBuffer::new(),
+            expires_at: 0,
+            score_value: 0,
+            score_decimals: 0,
+            compressed: false,
+            compression_algo: ManagedBuffer::new(),
+            flags,
+            fee_token: EgldOrEsdtTokenIdentifier::egld(),
+            epoch: self.blockchain().get_block_epoch(),
+            issuer_reputation_at_issue: self.issuer_reputation(issuer).get(),
+            region: ManagedBuffer::new(),
+            valid_from: 0,
+            document_size: 0,
+            valid_until_epoch: 0,
+            title: ManagedBuffer::new(),
+            checksum: ManagedBuffer::new(),
+        }
+    }
+    
+    fn load_proof(&self, proof_id: &ManagedBuffer) -> ProofData<Self::Api> {
+        require!(!self.proof_owners(proof_id).is_empty(), "Proof does not exist");
+        
+        let owner = self.proof_owners(proof_id).get();
+        self.user_proofs(&owner, proof_id).get()
+    }
+    
+    fn certify_internal(&self, caller: &ManagedAddress, mut proof_data: ProofData<Self::Api>, gating_nonce: u64) {
+        self.require_not_paused();
+        self.require_not_sealed();
+        
+        // Fara categorie explicita se aplica regulile de prefix, inainte de taxa si de reguli
+        if proof_data.category.is_empty() {
+            proof_data.category = self.derive_category(&proof_data.proof_id);
+        }
+        self.require_can_certify(&self.blockchain().get_caller(), &proof_data.issuer, &proof_data.category, gating_nonce);
+        
+        // Verifică dacă proof_id este unic
+        self.require_proof_id_available(&proof_data.proof_id, &proof_data.issuer);
+        
+        // Verifică lungimea proof_text
+        self.require_valid_proof_text(&proof_data.issuer, &proof_data.proof_text, proof_data.compressed);
+        
+        self.check_rate_limit(caller);
+        self.check_certify_cooldown(caller);
+        self.require_valid_proof_fields(&proof_data.metadata, &proof_data.category);
+        
+        if !proof_data.content_hash.is_empty() {
+            let hash_index = self.proof_by_content_hash(&proof_data.content_hash);
+            if hash_index.is_empty() {
+                hash_index.set(&proof_data.proof_id);
+            } else {
+                require!(
+                    !self.enforce_unique_content_hash().get(),
+                    "Content hash already certified"
+                );
+            }
+        }
+        
+        // Taxa depinde de categorie; verificata aici, nicio cale de certificare nu o poate ocoli
+        let (fee_token, payment) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_sufficient_fee(&proof_data.issuer, &proof_data.category, &fee_token, &payment);
+        proof_data.fee_paid = payment;
+        proof_data.fee_token = fee_token;
+        self.record_certification_fee(&proof_data.fee_token, &proof_data.fee_paid);
+        
+        let proof_id = proof_data.proof_id.clone();
+        let proof_text = proof_data.proof_text.clone();
+        let timestamp = proof_data.timestamp;
+        let issuer = proof_data.issuer.clone();
+        
+        self.store_new_proof(caller, proof_data);
+        
+        // Emit event
+        self.proof_certified_event(self.event_version(), caller, &proof_id, &proof_text, timestamp);
+        self.notify_proof_listener(&proof_id, caller);
+        self.pay_certification_reward(&issuer);
+    }
+    
+    // Regulile de schema comune tuturor cailor de emitere, reverificate si la finalizarea prin cvorum
+    fn require_valid_proof_fields(&self, metadata: &ManagedBuffer, category: &ManagedBuffer) {
+        self.require_valid_metadata(metadata);
+        
+        // Metadata trebuie sa inceapa cu tag-ul de schema cerut, daca exista
+        let required_prefix = self.required_metadata_prefix().get();
+        if !required_prefix.is_empty() {
+            require!(
+                metadata.len() >= required_prefix.len()
+                    && metadata.copy_slice(0, required_prefix.len()) == Some(required_prefix),
+                "Metadata schema mismatch"
+            );
+        }
+        
+        // Categoriile trebuie sa fie in allowlist cand aceasta este activa
+        if !category.is_empty() && self.enforce_category_allowlist().get() {
+            require!(
+                self.allowed_categories().contains(category),
+                "Unknown category"
+            );
+        }
+    }
+    
+    fn record_certification_fee(&self, fee_token: &EgldOrEsdtTokenIdentifier, fee_paid: &BigUint) {
+        if *fee_paid == 0 {
+            return;
+        }
+        
+        if fee_token.is_egld() {
+            self.total_fees_collected().update(|total| *total += fee_paid);
+        }
+        self.tracked_fee_balance(fee_token).update(|reserve| *reserve += fee_paid);
+        
+        let units = self.to_fee_units(fee_token, fee_paid);
+        self.total_fees_in_unit().update(|total| *total += units);
+    }
+    
+    /// Taxa de certificare se plateste la propunere si ramane in contract pana la cvorum.
+    #[payable("*")]
+    #[endpoint]
+    fn propose_proof(
+        &self,
+        proof_id: ManagedBuffer,
+        proof_text: ManagedBuffer,
+        metadata: ManagedBuffer,
+        required_sigs: u32,
+        gating_nonce: OptionalValue<u64>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let gating_nonce = gating_nonce.into_option().unwrap_or_default();
+        self.require_not_paused();
+        self.require_not_sealed();
+        
+        let category = self.derive_category(&proof_id);
+        self.require_can_certify(&caller, &caller, &category, gating_nonce);
+        
+        self.require_proof_id_available(&proof_id, &caller);
+        
+        self.require_valid_proof_text(&caller, &proof_text, false);
+        
+        // Limitele de frecventa se consuma o singura data, la propunere
+        self.check_rate_limit(&caller);
+        self.check_certify_cooldown(&caller);
+        self.require_valid_proof_fields(&metadata, &category);
+        
+        require!(
+            required_sigs > 0 && required_sigs as usize <= self.authorized_issuers().len(),
+            "Invalid required signatures"
+        );
+        
+        let (fee_token, payment) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_sufficient_fee(&caller, &category, &fee_token, &payment);
+        
+        self.pending_proofs(&proof_id).set(PendingProof {
+            proposer: caller.clone(),
+            proof_text,
+            metadata,
+            required_sigs,
+            fee_paid: payment,
+            fee_token,
+            gating_nonce,
+        });
+        
+        self.proof_proposed_event(self.event_version(), &caller, &proof_id, required_sigs);
+    }
+    
+    #[endpoint]
+    fn approve_proof(&self, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_sealed();
+        
+        require!(
+            self.authorized_issuers().contains(&caller),
+            "Only authorized issuers can approve"
+        );
+        require!(
+            !self.pending_proofs(&proof_id).is_empty(),
+            "Proof is not pending"
+        );
+        require!(
+            self.proof_approvals(&proof_id).insert(caller.clone()),
+            "Proof already approved by caller"
+        );
+        
+        self.proof_approved_event(self.event_version(), &caller, &proof_id);
+        
+        let pending = self.pending_proofs(&proof_id).get();
+        if (self.proof_approvals(&proof_id).len() as u32) < pending.required_sigs {
+            return;
+        }
+        
+        // Cvorum atins - regulile emitentului sunt reverificate, pot fi schimbate intre timp
+        let mut proof_data = self.new_proof_data(&pending.proposer, proof_id.clone(), pending.proof_text.clone(), pending.metadata);
+        proof_data.category = self.derive_category(&proof_id);
+        self.require_can_certify(&pending.proposer, &pending.proposer, &proof_data.category, pending.gating_nonce);
+        self.require_valid_proof_fields(&proof_data.metadata, &proof_data.category);
+        let timestamp = proof_data.timestamp;
+        self.record_certification_fee(&pending.fee_token, &pending.fee_paid);
+        proof_data.fee_paid = pending.fee_paid;
+        proof_data.fee_token = pending.fee_token;
+        
+        self.store_new_proof(&pending.proposer, proof_data);
+        self.pending_proofs(&proof_id).clear();
+        self.proof_approvals(&proof_id).clear();
+        
+        self.proof_certified_event(self.event_version(), &pending.proposer, &proof_id, &pending.proof_text, timestamp);
+        self.notify_proof_listener(&proof_id, &pending.proposer);
+        self.pay_certification_reward(&pending.proposer);
+    }
+    
+    // Apel fire-and-forget dupa ce storage-ul este actualizat; fara listener nu face nimic
+    fn notify_proof_listener(&self, proof_id: &ManagedBuffer, owner: &ManagedAddress) {
+        if self.proof_listener().is_empty() {
+            return;
+        }
+        
+        self.tx()
+            .to(self.proof_listener().get())
+            .gas(LISTENER_CALL_GAS)
+            .raw_call(PROOF_LISTENER_ENDPOINT)
+            .argument(proof_id)
+            .argument(owner)
+            .transfer_execute();
+    }
+    
+    fn check_rate_limit(&self, caller: &ManagedAddress) {
+        let max_per_window = self.rate_limit_max().get();
+        if max_per_window == 0 {
+            return;
+        }
+        
+        let now = self.blockchain().get_block_timestamp();
+        let window_start = self.rate_window_start(caller).get();
+        
+        // Fereastra a expirat - o resetam
+        if now >= window_start + self.rate_limit_window().get() {
+            self.rate_window_start(caller).set(now);
+            self.rate_window_count(caller).set(1);
+            return;
+        }
+        
+        let count = self.rate_window_count(caller).get();
+        require!(count < max_per_window, "Rate limit exceeded");
+        self.rate_window_count(caller).set(count + 1);
+    }
+    
+    fn check_certify_cooldown(&self, caller: &ManagedAddress) {
+        let cooldown = self.certify_cooldown().get();
+        if cooldown == 0 {
+            return;
+        }
+        
+        let now = self.blockchain().get_block_timestamp();
+        let last_certify = self.last_certify_timestamp(caller);
+        if !last_certify.is_empty() {
+            require!(now >= last_certify.get() + cooldown, "Certify cooldown active");
+        }
+        last_certify.set(now);
+    }
+    
+    // Aceleasi reguli ca `isProofIdAvailable`, dar cu eroarea specifica fiecarui caz
+    fn require_proof_id_available(&self, proof_id: &ManagedBuffer, issuer: &ManagedAddress) {
+        require!(
+            self.proof_owners(proof_id).is_empty()
+                && self.pending_proofs(proof_id).is_empty()
+                && self.archived_proofs(proof_id).is_empty(),
+            "Proof ID already exists"
+        );
+        if self.enforce_safe_ids().get() {
+            require!(self.is_safe_proof_id(proof_id), "Proof ID contains invalid characters");
+        }
+        self.check_id_reuse_cooldown(proof_id);
+        require!(
+            !self.is_prefix_reserved_for_other(proof_id, issuer),
+            "Proof ID prefix is reserved"
+        );
+    }
+    
+    fn check_id_reuse_cooldown(&self, proof_id: &ManagedBuffer) {
+        require!(!self.is_in_reuse_cooldown(proof_id), "Proof ID recently deleted");
+        self.deleted_proof_timestamp(proof_id).clear();
+    }
+    
+    fn is_in_reuse_cooldown(&self, proof_id: &ManagedBuffer) -> bool {
+        let cooldown = self.id_reuse_cooldown().get();
+        let tombstone = self.deleted_proof_timestamp(proof_id);
+        if cooldown == 0 || tombstone.is_empty() {
+            return false;
+        }
+        
+        self.blockchain().get_block_timestamp() < tombstone.get() + cooldown
+    }
+    
+    // Doar caractere URL-safe: alfanumerice, '_' si '-'
+    fn is_safe_proof_id(&self, proof_id: &ManagedBuffer) -> bool {
+        let mut safe = true;
+        proof_id.for_each_batch::<32, _>(|batch| {
+            safe &= batch
+                .iter()
+                .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_' || *byte == b'-');
+        });
+        safe
+    }
+    
+    /// Mentine clasamentul sortat la fiecare atestare. Costul de scriere este
+    /// O(MAX_TOP_PROOFS) per atestare, motiv pentru care clasamentul este limitat.
+    fn update_leaderboard(&self, proof_id: &ManagedBuffer, attestations: u32) {
+        let mut top = self.top_proofs();
+        let entry = RankedProof {
+            proof_id: proof_id.clone(),
+            attestations,
+        };
+        
+        let mut position = 0usize;
+        for index in 1..=top.len() {
+            if &top.get(index).proof_id == proof_id {
+                position = index;
+                break;
+            }
+        }
+        
+        if position == 0 {
+            if top.len() < MAX_TOP_PROOFS {
+                position = top.push(&entry);
+            } else if top.get(top.len()).attestations < attestations {
+                position = top.len();
+                top.set(position, &entry);
+            } else {
+                return;
+            }
+        } else {
+            top.set(position, &entry);
+        }
+        
+        // Urca intrarea pana la pozitia corecta
+        while position > 1 && top.get(position - 1).attestations < attestations {
+            let previous = top.get(position - 1);
+            top.set(position - 1, &entry);
+            top.set(position, &previous);
+            position -= 1;
+        }
+    }
+    
+    fn remove_from_leaderboard(&self, proof_id: &ManagedBuffer) {
+        let mut top = self.top_proofs();
+        let mut found = false;
+        
+        // Pastreaza ordinea: muta elementele urmatoare cu o pozitie in sus
+        for index in 1..=top.len() {
+            if found {
+                let current = top.get(index);
+                top.set(index - 1, &current);
+            } else if &top.get(index).proof_id == proof_id {
+                found = true;
+            }
+        }
+        
+        if found {
+            let last = top.len();
+            top.swap_remove(last);
+        }
+    }
+    
+    fn require_valid_proof_text(&self, issuer: &ManagedAddress, proof_text: &ManagedBuffer, compressed: bool) {
+        let len = proof_text.len() as u32;
+        if !self.issuer_text_limit(issuer).is_empty() {
+            // Limitele emitentului se aplica si payload-urilor comprimate, plafonate la 4096
+            let (min, mut max) = self.issuer_text_limit(issuer).get();
+            if compressed {
+                max = max.min(MAX_COMPRESSED_TEXT_LEN as u32);
+            }
+            require!(len >= min && len <= max, "Proof text length outside issuer limits");
+        } else if compressed {
+            let (min, _) = self.global_text_bounds();
+            let max = MAX_COMPRESSED_TEXT_LEN as u32;
+            require!(
+                len >= min && len <= max,
+                "Compressed proof text must be between {} and {} bytes",
+                min,
+                max
+            );
+        } else {
+            let (min, max) = self.global_text_bounds();
+            require!(
+                len >= min && len <= max,
+                "Proof text must be between {} and {} characters",
+                min,
+                max
+            );
+        }
+        
+        self.require_no_banned_content(proof_text);
+    }
+    
+    // Scaneaza octetii bruti; la payload-urile comprimate prinde doar tiparele ramase literale
+    fn require_no_banned_content(&self, proof_text: &ManagedBuffer) {
+        let banned = self.banned_substrings();
+        if banned.is_empty() {
+            return;
+        }
+        
+        let mut text_buffer = [0u8; MAX_COMPRESSED_TEXT_LEN];
+        let text = proof_text.load_to_byte_array(&mut text_buffer);
+        
+        for substring in banned.iter() {
+            let mut pattern_buffer = [0u8; MAX_BANNED_SUBSTRING_LEN];
+            let pattern = substring.load_to_byte_array(&mut pattern_buffer);
+            require!(
+                !text.windows(pattern.len()).any(|window| window == pattern),
+                "Proof text contains banned content"
+            );
+        }
+    }
+    
+    fn require_valid_metadata(&self, metadata: &ManagedBuffer) {
+        require!(metadata.len() <= MAX_METADATA_LEN, "Metadata too long");
+    }
+    
+    fn require_valid_title(&self, title: &ManagedBuffer) {
+        require!(title.len() <= MAX_TITLE_LEN, "Title too long");
+    }
+    
+    fn store_new_proof(&self, owner: &ManagedAddress, proof_data: ProofData<Self::Api>) {
+        let proof_id = proof_data.proof_id.clone();
+        let issuer = proof_data.issuer.clone();
+        
+        if !proof_data.category.is_empty() {
+            self.category_count(&proof_data.category).update(|count| *count += 1);
+            self.user_category_count(owner, &proof_data.category).update(|count| *count += 1);
+        }
+        if !proof_data.region.is_empty() {
+            self.proofs_by_region(&proof_data.region).insert(proof_id.clone());
+        }
+        
+        // Salvează dovada
+        self.save_proof(owner, &proof_id, proof_data);
+        
+        // Adaugă proof_id în lista utilizatorului
+        self.user_proof_ids(owner).insert(proof_id.clone());
+        
+        // Mapează proof_id la owner
+        self.proof_owners(&proof_id).set(owner.clone());
+        self.proof_holders().insert(owner.clone());
+        self.all_proof_ids().insert(proof_id.clone());
+        self.known_issuers().insert(issuer.clone());
+        self.issuer_proof_count(&issuer).update(|count| *count += 1);
+        
+        // Incrementează contoarele
+        let current_count = self.user_proof_count(owner).get();
+        self.user_proof_count(owner).set(current_count + 1);
+        
+        let total = self.total_proofs().get();
+        self.total_proofs().set(total + 1);
+        
+        self.assign_block_sequence(&proof_id);
+    }
+    
+    // Toate scrierile ProofData trec pe aici ca checksum-ul sa ramana sincronizat
+    fn save_proof(&self, owner: &ManagedAddress, proof_id: &ManagedBuffer, mut proof_data: ProofData<Self::Api>) {
+        proof_data.checksum = self.compute_proof_checksum(&proof_data);
+        self.user_proofs(owner, proof_id).set(proof_data);
+    }
+    
+    fn compute_proof_checksum(&self, proof_data: &ProofData<Self::Api>) -> ManagedBuffer {
+        let mut unsealed = proof_data.clone();
+        unsealed.checksum = ManagedBuffer::new();
+        
+        let mut encoded = ManagedBuffer::new();
+        require!(
+            unsealed.top_encode(&mut encoded).is_ok(),
+            "Failed to encode proof data"
+        );
+        self.crypto().sha256(&encoded).as_managed_buffer().clone()
+    }
+    
+    // Secventa porneste de la 0 la fiecare bloc nou
+    fn assign_block_sequence(&self, proof_id: &ManagedBuffer) {
+        let block_nonce = self.blockchain().get_block_nonce();
+        let sequence = if self.sequence_block_nonce().get() == block_nonce {
+            self.block_sequence().get() + 1
+        } else {
+            self.sequence_block_nonce().set(block_nonce);
+            0
+        };
+        
+        self.block_sequence().set(sequence);
+        self.proof_sequence(proof_id).set(sequence);
+    }
+    
+    fn remove_proof_storage(&self, owner: &ManagedAddress, proof_id: &ManagedBuffer) {
+        let proof_data = self.user_proofs(owner, proof_id).take();
+        
+        self.user_proof_ids(owner).swap_remove(proof_id);
+        self.proof_owners(proof_id).clear();
+        self.all_proof_ids().swap_remove(proof_id);
+        self.issuer_proof_count(&proof_data.issuer).update(|count| *count -= 1);
+        
+        self.user_proof_count(owner).update(|count| *count -= 1);
+        self.total_proofs().update(|total| *total -= 1);
+        
+        // Curata indexurile secundare
+        if !proof_data.category.is_empty() {
+            self.category_count(&proof_data.category).update(|count| *count -= 1);
+            self.user_category_count(owner, &proof_data.category).update(|count| *count -= 1);
+        }
+        if !proof_data.region.is_empty() {
+            self.proofs_by_region(&proof_data.region).swap_remove(proof_id);
+        }
+        
+        if !proof_data.content_hash.is_empty()
+            && self.proof_by_content_hash(&proof_data.content_hash).get() == *proof_id
+        {
+            self.proof_by_content_hash(&proof_data.content_hash).clear();
+        }
+        
+        if !self.proof_collection(proof_id).is_empty() {
+            let collection_id = self.proof_collection(proof_id).take();
+            self.collection_proofs(&collection_id).swap_remove(proof_id);
+        }
+        
+        self.proof_editors(proof_id).clear();
+        self.proof_readers(proof_id).clear();
+        self.proof_co_owners(proof_id).clear();
+        self.proof_sequence(proof_id).clear();
+        self.escrow_condition_hash(proof_id).clear();
+        
+        // Legaturile spre alte dovezi dispar odata cu dovada
+        if !proof_data.superseded_by.is_empty() {
+            self.release_inbound_link(&proof_data.superseded_by);
+        }
+        let merged_into = self.merged_into(proof_id).take();
+        if !merged_into.is_empty() {
+            self.release_inbound_link(&merged_into);
+        }
+        
+        for attester in self.proof_attesters(proof_id).iter() {
+            self.attester_proofs(&attester).swap_remove(proof_id);
+            self.attestation_expiry(proof_id, &attester).clear();
+        }
+        self.proof_attesters(proof_id).clear();
+        self.remove_from_leaderboard(proof_id);
+        self.pending_transfer(proof_id).clear();
+        
+        if !self.proof_parent(proof_id).is_empty() {
+            let parent_id = self.proof_parent(proof_id).take();
+            self.proof_children(&parent_id).swap_remove(proof_id);
+        }
+        for child_id in self.proof_children(proof_id).iter() {
+            self.proof_parent(&child_id).clear();
+        }
+        self.proof_children(proof_id).clear();
+        self.parent_revoked(proof_id).clear();
+        self.clear_proof_flags(proof_id);
+        self.proof_referrer(proof_id).clear();
+        self.proof_view_count(proof_id).clear();
+        
+        // Tombstone pentru cooldown-ul de refolosire a id-ului
+        self.deleted_proof_timestamp(proof_id).set(self.blockchain().get_block_timestamp());
+    }
+    
+    fn release_inbound_link(&self, target_id: &ManagedBuffer) {
+        self.inbound_link_count(target_id).update(|count| *count = count.saturating_sub(1));
+    }
+    
+    fn clear_proof_flags(&self, proof_id: &ManagedBuffer) {
+        self.proof_flags(proof_id).clear();
+        self.proof_flaggers(proof_id).clear();
+        self.proof_flag_count(proof_id).clear();
+    }
+    
+    /// Marcheaza doar copiii directi ca suspecti; cascada nu coboara mai
+    /// departe pentru a tine costul de gas limitat.
+    fn flag_children_of_revoked(&self, parent_id: &ManagedBuffer) {
+        if !self.propagate_parent_revocation().get() {
+            return;
+        }
+        
+        let mut flagged = 0u32;
+        for child_id in self.proof_children(parent_id).iter() {
+            self.parent_revoked(&child_id).set(true);
+            flagged += 1;
+        }
+        
+        if flagged > 0 {
+            self.parent_revocation_propagated_event(self.event_version(), parent_id, flagged);
+        }
+    }
+    
+    // Aduce ambele scoruri la acelasi numar de zecimale inainte de comparare
+    fn score_greater_than(&self, value: u64, decimals: u8, other_value: u64, other_decimals: u8) -> bool {
+        let scale = core::cmp::max(decimals, other_decimals);
+        let ten = BigUint::from(10u32);
+        
+        let left = BigUint::from(value) * ten.pow((scale - decimals) as u32);
+        let right = BigUint::from(other_value) * ten.pow((scale - other_decimals) as u32);
+        
+        left > right
+    }
+    
+    // Taxa este denominata in EGLD; platile in ESDT se compara in unitati contabile
+    fn require_sufficient_fee(
+        &self,
+        caller: &ManagedAddress,
+        category: &ManagedBuffer,
+        token: &EgldOrEsdtTokenIdentifier,
+        payment: &BigUint,
+    ) {
+        let fee = self.effective_certification_fee(caller, category);
+        if token.is_egld() {
+            require!(payment >= &fee, "Insufficient certification fee");
+            return;
+        }
+        
+        require!(self.fee_token_rate(token).get() > 0, "Fee token not accepted");
+        if fee > 0 {
+            let required_units = self.to_fee_units(&EgldOrEsdtTokenIdentifier::egld(), &fee);
+            require!(
+                required_units > 0 && self.to_fee_units(token, payment) >= required_units,
+                "Insufficient certification fee"
+            );
+        }
+    }
+    
+    fn to_fee_units(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) -> BigUint {
+        amount * &self.fee_token_rate(token).get()
+    }
+    
+    fn effective_certification_fee(&self, issuer: &ManagedAddress, category: &ManagedBuffer) -> BigUint {
+        let fee = self.get_category_fee(category);
+        let discount_bps = self.reputation_discount_bps().get();
+        if discount_bps == 0 || self.issuer_reputation(issuer).get() < self.reputation_discount_min().get() {
+            return fee;
+        }
+        
+        let discount = &fee * &BigUint::from(discount_bps) / BigUint::from(BPS_DENOMINATOR);
+        fee - discount
+    }
+    
+    // Cel mai lung prefix care se potriveste castiga; fara potrivire categoria ramane goala
+    fn derive_category(&self, proof_id: &ManagedBuffer) -> ManagedBuffer {
+        let mut category = ManagedBuffer::new();
+        let mut best_len = 0usize;
+        
+        for (prefix, rule_category) in self.prefix_category_rules().iter() {
+            let prefix_len = prefix.len();
+            if prefix_len <= best_len || prefix_len > proof_id.len() {
+                continue;
+            }
+            if proof_id.copy_slice(0, prefix_len) == Some(prefix) {
+                best_len = prefix_len;
+                category = rule_category;
+            }
+        }
+        
+        category
+    }
+    
+    fn is_expired(&self, proof_data: &ProofData<Self::Api>) -> bool {
+        if proof_data.valid_until_epoch > 0 {
+            return self.blockchain().get_block_epoch() >= proof_data.valid_until_epoch;
+        }
+        
+        proof_data.expires_at > 0 && self.blockchain().get_block_timestamp() >= proof_data.expires_at
+    }
+    
+    fn is_not_yet_valid(&self, proof_data: &ProofData<Self::Api>) -> bool {
+        self.blockchain().get_block_timestamp() < proof_data.valid_from
+    }
+    
+    /// Oricine poate sterge dovezile expirate; id-urile inexistente sau inca valide sunt ignorate.
+    /// Daca owner-ul a configurat o recompensa, apelantul o primeste din taxele acumulate.
+    #[endpoint]
+    fn sweep_expired(&self, proof_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        let mut swept = 0u32;
+        
+        for proof_id in proof_ids.into_iter() {
+            if self.proof_owners(&proof_id).is_empty() {
+                continue;
+            }
+            
+            let owner = self.proof_owners(&proof_id).get();
+            if !self.is_expired(&self.user_proofs(&owner, &proof_id).get()) {
+                continue;
+            }
+            
+            self.remove_proof_storage(&owner, &proof_id);
+            self.proof_swept_event(self.event_version(), &caller, &proof_id);
+            swept += 1;
+        }
+        
+        let bounty = self.sweep_bounty().get() * swept;
+        let reserve = self.fee_reserve().get();
+        let payout = if bounty > reserve { reserve } else { bounty };
+        
+        if payout > 0 {
+            self.fee_reserve().update(|reserve| *reserve -= &payout);
+            self.send().direct_egld(&caller, &payout);
+        }
+        
+        swept
+    }
+    
+    #[endpoint]
+    fn grant_editor(&self, proof_id: ManagedBuffer, editor: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can manage editors"
+        );
+        require!(editor != caller, "Owner is already allowed to edit");
+        
+        self.proof_editors(&proof_id).insert(editor.clone());
+        self.editor_granted_event(self.event_version(), &proof_id, &editor);
+    }
+    
+    #[endpoint]
+    fn revoke_editor(&self, proof_id: ManagedBuffer, editor: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can manage editors"
+        );
+        require!(
+            self.proof_editors(&proof_id).swap_remove(&editor),
+            "Address is not an editor"
+        );
+        
+        self.editor_revoked_event(self.event_version(), &proof_id, &editor);
+    }
+    
+    #[endpoint]
+    fn add_co_owner(&self, proof_id: ManagedBuffer, co_owner: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can manage co-owners"
+        );
+        require!(co_owner != caller, "Owner cannot be a co-owner");
+        
+        self.proof_co_owners(&proof_id).insert(co_owner);
+    }
+    
+    #[endpoint]
+    fn remove_co_owner(&self, proof_id: ManagedBuffer, co_owner: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can manage co-owners"
+        );
+        require!(
+            self.proof_co_owners(&proof_id).swap_remove(&co_owner),
+            "Address is not a co-owner"
+        );
+    }
+    
+    /// Analytics opt-in: costa gas, asa ca doar clientii care vor contorul il apeleaza.
+    #[endpoint]
+    fn record_view(&self, proof_id: ManagedBuffer) {
+        self.require_not_paused();
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        self.proof_view_count(&proof_id).update(|count| *count += 1);
+    }
+    
+    #[endpoint]
+    fn grant_reader(&self, proof_id: ManagedBuffer, reader: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can manage readers"
+        );
+        
+        self.proof_readers(&proof_id).insert(reader.clone());
+        self.reader_granted_event(self.event_version(), &proof_id, &reader);
+    }
+    
+    #[endpoint]
+    fn revoke_reader(&self, proof_id: ManagedBuffer, reader: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can manage readers"
+        );
+        require!(
+            self.proof_readers(&proof_id).swap_remove(&reader),
+            "Address is not a reader"
+        );
+        
+        self.reader_revoked_event(self.event_version(), &proof_id, &reader);
+    }
+    
+    #[endpoint]
+    fn update_proof(&self, proof_id: ManagedBuffer, new_proof_text: ManagedBuffer, new_metadata: OptionalValue<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_append_only();
+        
+        // Verifică ownership sau drept de editare delegat
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        let owner = self.proof_owners(&proof_id).get();
+        require!(
+            self.has_edit_rights(&proof_id, &owner, &caller),
+            "Only proof owner or editor can update"
+        );
+        
+        let mut proof_data = self.user_proofs(&owner, &proof_id).get();
+        self.require_editable(&proof_data);
+        self.require_valid_proof_text(&proof_data.issuer, &new_proof_text, proof_data.compressed);
+        let old_proof_text = core::mem::replace(&mut proof_data.proof_text, new_proof_text.clone());
+        
+        if let OptionalValue::Some(metadata) = new_metadata {
+            self.require_valid_metadata(&metadata);
+            proof_data.metadata = metadata;
+        }
+        
+        self.save_proof(&owner, &proof_id, proof_data);
+        
+        // Emit update event
+        if self.emit_update_diffs().get() {
+            let diff = ProofTextDiff {
+                old_text: old_proof_text,
+                new_text: new_proof_text.clone(),
+            };
+            self.proof_updated_detailed_event(self.event_version(), &caller, &proof_id, &diff);
+        }
+        self.proof_updated_event(self.event_version(), &caller, &proof_id, &new_proof_text);
+    }
+    
+    #[endpoint]
+    fn update_title(&self, proof_id: ManagedBuffer, new_title: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_append_only();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        let owner = self.proof_owners(&proof_id).get();
+        require!(
+            self.has_edit_rights(&proof_id, &owner, &caller),
+            "Only proof owner or editor can update"
+        );
+        self.require_valid_title(&new_title);
+        
+        let mut proof_data = self.user_proofs(&owner, &proof_id).get();
+        self.require_editable(&proof_data);
+        
+        proof_data.title = new_title;
+        self.save_proof(&owner, &proof_id, proof_data);
+        
+        self.title_updated_event(self.event_version(), &caller, &proof_id);
+    }
+    
+    // Regula comuna pentru endpoint-urile de editare si pentru canEditProof
+    fn edit_denial_reason(&self, proof_data: &ProofData<Self::Api>) -> Option<&'static str> {
+        if proof_data.is_frozen() {
+            Some("Proof is frozen")
+        } else if proof_data.is_revoked() {
+            Some("Proof is revoked")
+        } else if proof_data.is_immutable() {
+            Some("Proof is immutable")
+        } else {
+            None
+        }
+    }
+    
+    fn require_editable(&self, proof_data: &ProofData<Self::Api>) {
+        if let Some(reason) = self.edit_denial_reason(proof_data) {
+            sc_panic!(reason);
+        }
+    }
+    
+    // Owner-ul, co-ownerii si editorii delegati pot edita; transferul ramane doar al owner-ului
+    fn has_edit_rights(&self, proof_id: &ManagedBuffer, owner: &ManagedAddress, address: &ManagedAddress) -> bool {
+        owner == address
+            || self.proof_co_owners(proof_id).contains(address)
+            || self.proof_editors(proof_id).contains(address)
+    }
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn transfer_proof_ownership(&self, proof_id: ManagedBuffer, new_owner: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == self.transfer_fee().get(), "Incorrect transfer fee");
+        if payment > 0 {
+            self.total_fees_collected().update(|total| *total += &payment);
+            self.fee_reserve().update(|reserve| *reserve += &payment);
+        }
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can transfer"
+        );
+        require!(new_owner != caller, "Cannot transfer to self");
+        
+        self.move_proof(&caller, &new_owner, &proof_id);
+    }
+    
+    /// Transfera doar dovezile detinute de apelant dintre cele cerute; restul sunt ignorate.
+    /// Taxa de transfer se plateste pentru fiecare dovada mutata. Returneaza numarul mutat.
+    #[payable("EGLD")]
+    #[endpoint]
+    fn transfer_proofs(&self, proof_ids: MultiValueEncoded<ManagedBuffer>, new_owner: ManagedAddress) -> u32 {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        require!(new_owner != caller, "Cannot transfer to self");
+        
+        let mut moved = 0u32;
+        for proof_id in proof_ids {
+            if self.user_proofs(&caller, &proof_id).is_empty() {
+                continue;
+            }
+            
+            self.move_proof(&caller, &new_owner, &proof_id);
+            moved += 1;
+        }
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == self.transfer_fee().get() * moved, "Incorrect transfer fee");
+        if payment > 0 {
+            self.total_fees_collected().update(|total| *total += &payment);
+            self.fee_reserve().update(|reserve| *reserve += &payment);
+        }
+        
+        moved
+    }
+    
+    #[endpoint]
+    fn offer_transfer(&self, proof_id: ManagedBuffer, to: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can transfer"
+        );
+        require!(to != caller, "Cannot transfer to self");
+        
+        self.pending_transfer(&proof_id).set(&to);
+        self.transfer_offered_event(self.event_version(), &caller, &to, &proof_id);
+    }
+    
+    /// Destinatarul plateste taxa de transfer la acceptare, ca la `transfer_proof_ownership`.
+    #[payable("EGLD")]
+    #[endpoint]
+    fn accept_transfer(&self, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(
+            !self.pending_transfer(&proof_id).is_empty() && self.pending_transfer(&proof_id).get() == caller,
+            "No pending transfer for caller"
+        );
+        
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == self.transfer_fee().get(), "Incorrect transfer fee");
+        if payment > 0 {
+            self.total_fees_collected().update(|total| *total += &payment);
+            self.fee_reserve().update(|reserve| *reserve += &payment);
+        }
+        
+        // Ownership-ul se schimba doar la acceptare
+        let owner = self.proof_owners(&proof_id).get();
+        self.move_proof(&owner, &caller, &proof_id);
+    }
+    
+    #[endpoint]
+    fn cancel_transfer(&self, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(
+            !self.pending_transfer(&proof_id).is_empty(),
+            "No pending transfer"
+        );
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can cancel transfer"
+        );
+        
+        let to = self.pending_transfer(&proof_id).take();
+        self.transfer_cancelled_event(self.event_version(), &caller, &to, &proof_id);
+    }
+    
+    /// Corecteaza un proof_id gresit; datele si toate indexurile trec pe noul id.
+    #[endpoint]
+    fn rename_proof(&self, old_id: ManagedBuffer, new_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_append_only();
+        
+        require!(!self.proof_owners(&old_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&old_id).get() == caller,
+            "Only proof owner can rename"
+        );
+        
+        let mut proof_data = self.user_proofs(&caller, &old_id).take();
+        self.require_proof_id_available(&new_id, &proof_data.issuer);
+        require!(!proof_data.is_frozen(), "Proof is frozen");
+        require!(!proof_data.is_immutable(), "Proof is immutable");
+        // superseded_by si mergedInto ale altor dovezi ar ramane spre id-ul vechi
+        require!(
+            self.inbound_link_count(&old_id).get() == 0,
+            "Proof is referenced by another proof"
+        );
+        
+        if !proof_data.content_hash.is_empty()
+            && self.proof_by_content_hash(&proof_data.content_hash).get() == old_id
+        {
+            self.proof_by_content_hash(&proof_data.content_hash).set(&new_id);
+        }
+        if !proof_data.region.is_empty() {
+            self.proofs_by_region(&proof_data.region).swap_remove(&old_id);
+            self.proofs_by_region(&proof_data.region).insert(new_id.clone());
+        }
+        
+        proof_data.proof_id = new_id.clone();
+        self.save_proof(&caller, &new_id, proof_data);
+        self.user_proof_ids(&caller).swap_remove(&old_id);
+        self.user_proof_ids(&caller).insert(new_id.clone());
+        self.proof_owners(&old_id).clear();
+        self.proof_owners(&new_id).set(&caller);
+        self.all_proof_ids().swap_remove(&old_id);
+        self.all_proof_ids().insert(new_id.clone());
+        
+        self.rename_proof_indexes(&old_id, &new_id);
+        
+        // Vechiul id nu poate fi preluat imediat de altcineva
+        self.deleted_proof_timestamp(&old_id).set(self.blockchain().get_block_timestamp());
+        self.proof_renamed_event(self.event_version(), &caller, &old_id, &new_id);
+    }
+    
+    fn rename_proof_indexes(&self, old_id: &ManagedBuffer, new_id: &ManagedBuffer) {
+        if !self.proof_collection(old_id).is_empty() {
+            let collection_id = self.proof_collection(old_id).take();
+            self.collection_proofs(&collection_id).swap_remove(old_id);
+            self.collection_proofs(&collection_id).insert(new_id.clone());
+            self.proof_collection(new_id).set(collection_id);
+        }
+        
+        for editor in self.proof_editors(old_id).iter() {
+            self.proof_editors(new_id).insert(editor);
+        }
+        self.proof_editors(old_id).clear();
+        
+        for reader in self.proof_readers(old_id).iter() {
+            self.proof_readers(new_id).insert(reader);
+        }
+        self.proof_readers(old_id).clear();
+        
+        for co_owner in self.proof_co_owners(old_id).iter() {
+            self.proof_co_owners(new_id).insert(co_owner);
+        }
+        self.proof_co_owners(old_id).clear();
+        
+        for attester in self.proof_attesters(old_id).iter() {
+            self.attester_proofs(&attester).swap_remove(old_id);
+            self.attester_proofs(&attester).insert(new_id.clone());
+            if !self.attestation_expiry(old_id, &attester).is_empty() {
+                let expires_at = self.attestation_expiry(old_id, &attester).take();
+                self.attestation_expiry(new_id, &attester).set(expires_at);
+            }
+            self.proof_attesters(new_id).insert(attester);
+        }
+        self.proof_attesters(old_id).clear();
+        
+        if !self.escrow_condition_hash(old_id).is_empty() {
+            let condition_hash = self.escrow_condition_hash(old_id).take();
+            self.escrow_condition_hash(new_id).set(condition_hash);
+        }
+        
+        if !self.merged_into(old_id).is_empty() {
+            let canonical_id = self.merged_into(old_id).take();
+            self.merged_into(new_id).set(canonical_id);
+        }
+        
+        let mut top = self.top_proofs();
+        for index in 1..=top.len() {
+            let mut ranked = top.get(index);
+            if &ranked.proof_id == old_id {
+                ranked.proof_id = new_id.clone();
+                top.set(index, &ranked);
+                break;
+            }
+        }
+        
+        if !self.proof_parent(old_id).is_empty() {
+            let parent_id = self.proof_parent(old_id).take();
+            self.proof_children(&parent_id).swap_remove(old_id);
+            self.proof_children(&parent_id).insert(new_id.clone());
+            self.proof_parent(new_id).set(parent_id);
+        }
+        for child_id in self.proof_children(old_id).iter() {
+            self.proof_parent(&child_id).set(new_id);
+            self.proof_children(new_id).insert(child_id);
+        }
+        self.proof_children(old_id).clear();
+        
+        for flag in self.proof_flags(old_id).iter() {
+            self.proof_flags(new_id).push(&flag);
+        }
+        for flagger in self.proof_flaggers(old_id).iter() {
+            self.proof_flaggers(new_id).insert(flagger);
+        }
+        self.proof_flag_count(new_id).set(self.proof_flag_count(old_id).get());
+        self.clear_proof_flags(old_id);
+        
+        self.parent_revoked(new_id).set(self.parent_revoked(old_id).take());
+        self.proof_sequence(new_id).set(self.proof_sequence(old_id).take());
+        if !self.pending_transfer(old_id).is_empty() {
+            self.pending_transfer(new_id).set(self.pending_transfer(old_id).take());
+        }
+        if !self.proof_referrer(old_id).is_empty() {
+            self.proof_referrer(new_id).set(self.proof_referrer(old_id).take());
+        }
+        self.proof_view_count(new_id).set(self.proof_view_count(old_id).take());
+    }
+    
+    fn move_proof(&self, from: &ManagedAddress, to: &ManagedAddress, proof_id: &ManagedBuffer) {
+        // Muta dovada la noul owner - issuer ramane neschimbat
+        let proof_data = self.user_proofs(from, proof_id).take();
+        require!(!proof_data.is_frozen(), "Proof is frozen");
+        
+        if !proof_data.category.is_empty() {
+            self.user_category_count(from, &proof_data.category).update(|count| *count -= 1);
+            self.user_category_count(to, &proof_data.category).update(|count| *count += 1);
+        }
+        
+        self.save_proof(to, proof_id, proof_data);
+        
+        self.user_proof_ids(from).swap_remove(proof_id);
+        self.user_proof_ids(to).insert(proof_id.clone());
+        self.proof_owners(proof_id).set(to.clone());
+        self.proof_holders().insert(to.clone());
+        
+        // Editorii si ofertele de transfer au fost facute de fostul owner
+        self.proof_editors(proof_id).clear();
+        self.proof_readers(proof_id).clear();
+        self.proof_co_owners(proof_id).clear();
+        self.pending_transfer(proof_id).clear();
+        
+        self.user_proof_count(from).update(|count| *count -= 1);
+        self.user_proof_count(to).update(|count| *count += 1);
+        
+        self.proof_transferred_event(self.event_version(), from, to, proof_id);
+    }
+    
+    #[endpoint]
+    fn attest_proof(&self, proof_id: ManagedBuffer, valid_for_seconds: OptionalValue<u64>) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        let proof_data = self.load_proof(&proof_id);
+        require!(!proof_data.is_revoked(), "Proof is revoked");
+        require!(self.merged_into(&proof_id).is_empty(), "Proof was merged");
+        require!(
+            self.proof_owners(&proof_id).get() != caller,
+            "Owner cannot attest own proof"
+        );
+        require!(
+            self.proof_attesters(&proof_id).insert(caller.clone()),
+            "Proof already attested by caller"
+        );
+        self.attester_proofs(&caller).insert(proof_id.clone());
+        if let OptionalValue::Some(validity) = valid_for_seconds {
+            if validity > 0 {
+                // Fara overflow-checks o valoare uriasa ar face wrap intr-un moment din trecut
+                let expires_at = match self.blockchain().get_block_timestamp().checked_add(validity) {
+                    Some(expires_at) => expires_at,
+                    None => sc_panic!("Attestation validity too long"),
+                };
+                self.attestation_expiry(&proof_id, &caller).set(expires_at);
+            }
+        }
+        self.issuer_reputation(&proof_data.issuer).update(|reputation| *reputation += 1);
+        self.update_leaderboard(&proof_id, self.proof_attesters(&proof_id).len() as u32);
+        
+        self.proof_attested_event(self.event_version(), &caller, &proof_id);
+    }
+    
+    /// Mentenanta: elimina atestarile expirate ale unei dovezi. Poate fi apelat de oricine.
+    #[endpoint(pruneExpiredAttestations)]
+    fn prune_expired_attestations(&self, proof_id: ManagedBuffer) -> u32 {
+        self.require_not_paused();
+        
+        let mut expired = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for attester in self.proof_attesters(&proof_id).iter() {
+            if self.is_attestation_expired(&proof_id, &attester) {
+                expired.push(attester);
+            }
+        }
+        
+        for attester in expired.iter() {
+            self.proof_attesters(&proof_id).swap_remove(&attester);
+            self.attester_proofs(&attester).swap_remove(&proof_id);
+            self.attestation_expiry(&proof_id, &attester).clear();
+        }
+        
+        let pruned = expired.len() as u32;
+        if pruned > 0 {
+            // Clasamentul doar urca intrari, asa ca dovada este reinserata cu noul numar
+            self.remove_from_leaderboard(&proof_id);
+            let remaining = self.proof_attesters(&proof_id).len() as u32;
+            if remaining > 0 {
+                self.update_leaderboard(&proof_id, remaining);
+            }
+        }
+        
+        pruned
+    }
+    
+    fn is_attestation_expired(&self, proof_id: &ManagedBuffer, attester: &ManagedAddress) -> bool {
+        let expiry = self.attestation_expiry(proof_id, attester);
+        !expiry.is_empty() && self.blockchain().get_block_timestamp() >= expiry.get()
+    }
+    
+    #[endpoint]
+    fn revoke_proof(&self, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        let owner = self.proof_owners(&proof_id).get();
+        let mut proof_data = self.user_proofs(&owner, &proof_id).get();
+        
+        // Owner-ul curent sau emitentul original pot revoca
+        require!(
+            caller == owner || caller == proof_data.issuer,
+            "Only proof owner or issuer can revoke"
+        );
+        require!(!proof_data.is_revoked(), "Proof already revoked");
+        
+        proof_data.set_revoked(true);
+        self.save_proof(&owner, &proof_id, proof_data);
+        
+        self.proof_revoked_event(self.event_version(), &caller, &proof_id);
+        self.flag_children_of_revoked(&proof_id);
+    }
+
+    /// Aplica (proof_id, new_metadata) doar pe dovezile detinute de apelant;
+    /// dovezile straine, inghetate, revocate sau imuabile sunt sarite.
+    #[endpoint(batchUpdateMetadata)]
+    fn batch_update_metadata(&self, updates: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>>) -> u32 {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_append_only();
+        
+        let mut updated = 0u32;
+        for update in updates {
+            let (proof_id, new_metadata) = update.into_tuple();
+            self.require_valid_metadata(&new_metadata);
+            
+            if self.user_proofs(&caller, &proof_id).is_empty() {
+                continue;
+            }
+            
+            let mut proof_data = self.user_proofs(&caller, &proof_id).get();
+            if self.edit_denial_reason(&proof_data).is_some() {
+                continue;
+            }
+            
+            proof_data.metadata = new_metadata;
+            self.save_proof(&caller, &proof_id, proof_data);
+            self.metadata_updated_event(self.event_version(), &caller, &proof_id);
+            updated += 1;
+        }
+        
+        updated
+    }
+
+    /// Sterge definitiv dovezile revocate ale apelantului; celelalte id-uri sunt ignorate.
+    #[endpoint(purgeRevoked)]
+    fn purge_revoked(&self, proof_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        let mut purged = 0u32;
+        
+        for proof_id in proof_ids {
+            if self.user_proofs(&caller, &proof_id).is_empty() {
+                continue;
+            }
+            if !self.user_proofs(&caller, &proof_id).get().is_revoked() {
+                continue;
+            }
+            
+            self.remove_proof_storage(&caller, &proof_id);
+            self.proof_purged_event(self.event_version(), &caller, &proof_id);
+            purged += 1;
+        }
+        
+        purged
+    }
+
+    #[endpoint(linkParentProof)]
+    fn link_parent_proof(&self, child_id: ManagedBuffer, parent_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(child_id != parent_id, "Proof cannot be its own parent");
+        require!(
+            !self.proof_owners(&child_id).is_empty() && !self.proof_owners(&parent_id).is_empty(),
+            "Proof does not exist"
+        );
+        require!(self.proof_owners(&child_id).get() == caller, "Only proof owner can link parent");
+        require!(self.proof_parent(&child_id).is_empty(), "Proof already has a parent");
+        
+        self.proof_parent(&child_id).set(&parent_id);
+        self.proof_children(&parent_id).insert(child_id.clone());
+        
+        // Copilul legat de un parinte deja revocat este suspect din start
+        let parent_owner = self.proof_owners(&parent_id).get();
+        if self.propagate_parent_revocation().get() && self.user_proofs(&parent_owner, &parent_id).get().is_revoked() {
+            self.parent_revoked(&child_id).set(true);
+        }
+        
+        self.parent_linked_event(self.event_version(), &parent_id, &child_id);
+    }
+
+    #[endpoint(flagProof)]
+    fn flag_proof(&self, proof_id: ManagedBuffer, reason: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(!reason.is_empty(), "Reason cannot be empty");
+        require!(
+            self.proof_flaggers(&proof_id).insert(caller.clone()),
+            "Proof already flagged by caller"
+        );
+        
+        self.proof_flags(&proof_id).push(&ProofFlag {
+            flagger: caller.clone(),
+            reason,
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+        self.proof_flag_count(&proof_id).update(|count| *count += 1);
+        
+        self.proof_flagged_event(self.event_version(), &caller, &proof_id);
+    }
+
+    #[endpoint]
+    fn supersede_proof(&self, old_id: ManagedBuffer, new_id: ManagedBuffer, revoke_old: bool) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_append_only();
+        
+        require!(old_id != new_id, "Proof cannot supersede itself");
+        require!(
+            !self.proof_owners(&old_id).is_empty() && !self.proof_owners(&new_id).is_empty(),
+            "Proof does not exist"
+        );
+        require!(
+            self.proof_owners(&old_id).get() == caller && self.proof_owners(&new_id).get() == caller,
+            "Only owner of both proofs can supersede"
+        );
+        
+        let mut old_proof = self.user_proofs(&caller, &old_id).get();
+        let new_proof = self.user_proofs(&caller, &new_id).get();
+        
+        // Noua versiune trebuie sa fie capatul unui lant - previne ciclurile
+        require!(old_proof.superseded_by.is_empty(), "Proof already superseded");
+        require!(new_proof.superseded_by.is_empty(), "New proof is already superseded");
+        
+        old_proof.superseded_by = new_id.clone();
+        let revoked_now = revoke_old && !old_proof.is_revoked();
+        if revoked_now {
+            old_proof.set_revoked(true);
+            self.proof_revoked_event(self.event_version(), &caller, &old_id);
+        }
+        self.save_proof(&caller, &old_id, old_proof);
+        self.inbound_link_count(&new_id).update(|count| *count += 1);
+        if revoked_now {
+            self.flag_children_of_revoked(&old_id);
+        }
+        
+        self.proof_superseded_event(self.event_version(), &old_id, &new_id);
+    }
+    
+    /// Contopeste un duplicat in dovada canonica: atestarile lui `merge_id` trec pe `keep_id`,
+    /// iar `merge_id` ramane stocata doar ca redirectionare (vezi `resolveProofId`).
+    #[endpoint(mergeProofs)]
+    fn merge_proofs(&self, keep_id: ManagedBuffer, merge_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        self.require_not_append_only();
+        
+        require!(keep_id != merge_id, "Proof cannot be merged into itself");
+        require!(
+            !self.proof_owners(&keep_id).is_empty() && !self.proof_owners(&merge_id).is_empty(),
+            "Proof does not exist"
+        );
+        require!(
+            self.proof_owners(&keep_id).get() == caller && self.proof_owners(&merge_id).get() == caller,
+            "Only owner of both proofs can merge"
+        );
+        // Dovada pastrata trebuie sa fie canonica - previne ciclurile
+        require!(self.merged_into(&merge_id).is_empty(), "Proof already merged");
+        require!(self.merged_into(&keep_id).is_empty(), "Kept proof is already merged");
+        
+        for attester in self.proof_attesters(&merge_id).iter() {
+            self.attester_proofs(&attester).swap_remove(&merge_id);
+            let expires_at = self.attestation_expiry(&merge_id, &attester).take();
+            
+            // Atestarile duble se pastreaza o singura data
+            if self.proof_attesters(&keep_id).insert(attester.clone()) {
+                self.attester_proofs(&attester).insert(keep_id.clone());
+                if expires_at > 0 {
+                    self.attestation_expiry(&keep_id, &attester).set(expires_at);
+                }
+            }
+        }
+        self.proof_attesters(&merge_id).clear();
+        self.remove_from_leaderboard(&merge_id);
+        self.update_leaderboard(&keep_id, self.proof_attesters(&keep_id).len() as u32);
+        
+        self.merged_into(&merge_id).set(&keep_id);
+        self.inbound_link_count(&keep_id).update(|count| *count += 1);
+        
+        self.proof_merged_event(self.event_version(), &caller, &keep_id, &merge_id);
+    }
+
+    #[endpoint]
+    fn bookmark_proof(&self, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        self.user_bookmarks(&caller).insert(proof_id);
+    }
+    
+    #[endpoint]
+    fn unbookmark_proof(&self, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(
+            self.user_bookmarks(&caller).swap_remove(&proof_id),
+            "Proof is not bookmarked"
+        );
+    }
+    
+    #[endpoint]
+    fn create_collection(&self, collection_id: ManagedBuffer, name: ManagedBuffer, metadata: OptionalValue<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(!collection_id.is_empty(), "Collection ID cannot be empty");
+        require!(
+            self.collections(&collection_id).is_empty(),
+            "Collection already exists"
+        );
+        
+        let metadata_buffer = match metadata {
+            OptionalValue::Some(meta) => meta,
+            OptionalValue::None => ManagedBuffer::new(),
+        };
+        
+        self.collections(&collection_id).set(ProofCollection {
+            owner: caller.clone(),
+            name,
+            metadata: metadata_buffer,
+            created_at: self.blockchain().get_block_timestamp(),
+        });
+        
+        self.collection_created_event(self.event_version(), &caller, &collection_id);
+    }
+    
+    #[endpoint]
+    fn add_to_collection(&self, collection_id: ManagedBuffer, proof_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_paused();
+        
+        require!(
+            !self.collections(&collection_id).is_empty(),
+            "Collection does not exist"
+        );
+        require!(
+            self.collections(&collection_id).get().owner == caller,
+            "Only collection owner can add proofs"
+        );
+        require!(!self.proof_owners(&proof_id).is_empty(), "Proof does not exist");
+        require!(
+            self.proof_owners(&proof_id).get() == caller,
+            "Only proof owner can add to collection"
+        );
+        require!(
+            self.proof_collection(&proof_id).is_empty(),
+            "Proof already belongs to a collection"
+        );
+        
+        self.collection_proofs(&collection_id).insert(proof_id.clone());
+        self.proof_collection(&proof_id).set(&collection_id);
+        
+        self.proof_added_to_collection_event(self.event_version(), &collection_id, &proof_id);
+    }
+    
+    /// Ingheata dovezile unui utilizator (blocare de conformitate), paginat dupa `from`/`size`.
+    /// Returneaza `true` daca mai raman dovezi de procesat.
+    #[only_owner]
+    #[endpoint(freezeAllProofs)]
+    fn freeze_all_proofs(&self, user: ManagedAddress, from: usize, size: usize) -> bool {
+        self.set_frozen_page(&user, from, size, true)
+    }
+    
+    #[only_owner]
+    #[endpoint(unfreezeAllProofs)]
+    fn unfreeze_all_proofs(&self, user: ManagedAddress, from: usize, size: usize) -> bool {
+        self.set_frozen_page(&user, from, size, false)
+    }
+    
+    fn set_frozen_page(&self, user: &ManagedAddress, from: usize, size: usize, frozen: bool) -> bool {
+        let proof_ids = self.user_proof_ids(user);
+        let end = core::cmp::min(from + size, proof_ids.len());
+        let mut changed = 0u32;
+        
+        for index in (from + 1)..=end {
+            let proof_id = proof_ids.get_by_index(index);
+            let mut proof_data = self.user_proofs(user, &proof_id).get();
+            if proof_data.is_frozen() == frozen {
+                continue;
+            }
+            
+            proof_data.set_frozen(frozen);
+            self.save_proof(user, &proof_id, proof_data);
+            changed += 1;
+        }
+        
+        if frozen {
+            self.bulk_frozen_event(self.event_version(), user, changed);
+        } else {
+            self.bulk_unfrozen_event(self.event_version(), user, changed);
+        }
+        
+        end < proof_ids.len()
+    }
+    
+    /// Re-encodeaza in layout-ul curent dovezile unui utilizator salvate de prima versiune
+    /// a contractului (proof_text, timestamp, proof_id, metadata), care altfel nu mai pot fi
+    /// decodate dupa upgrade. Emitentul devine owner-ul, iar indexurile globale introduse
+    /// ulterior sunt completate; contoarele existau deja. Inregistrarile in layout-ul curent
+    /// sunt sarite, deci apelul poate fi repetat. Returneaza `true` cat timp mai sunt pagini.
+    /// Ramane permisa in modul append-only: schimba doar codificarea, nu si continutul dovezii.
+    #[only_owner]
+    #[endpoint(migrateProofs)]
+    fn migrate_proofs(&self, user: ManagedAddress, from: usize, size: usize) -> bool {
+        let proof_ids = self.user_proof_ids(&user);
+        let end = core::cmp::min(from + size, proof_ids.len());
+        
+        for index in (from + 1)..=end {
+            let proof_id = proof_ids.get_by_index(index);
+            let raw = self.user_proofs_raw(&user, &proof_id).get();
+            if ProofData::<Self::Api>::top_decode(raw.clone()).is_ok() {
+                continue;
+            }
+            
+            let legacy = match LegacyProofData::<Self::Api>::top_decode(raw) {
+                Ok(legacy) => legacy,
+                Err(_) => sc_panic!("Unknown proof layout"),
+            };
+            
+            let mut proof_data = self.new_proof_data(&user, legacy.proof_id, legacy.proof_text, legacy.metadata);
+            proof_data.timestamp = legacy.timestamp;
+            // Setarile curente nu se aplica retroactiv; epoca si reputatia nu erau inregistrate
+            proof_data.flags = FLAGS_LAYOUT_VERSION << 8;
+            proof_data.epoch = 0;
+            proof_data.issuer_reputation_at_issue = 0;
+            self.save_proof(&user, &proof_id, proof_data);
+            
+            self.proof_holders().insert(user.clone());
+            self.all_proof_ids().insert(proof_id);
+            self.known_issuers().insert(user.clone());
+            self.issuer_proof_count(&user).update(|count| *count += 1);
+        }
+        
+        end < proof_ids.len()
+    }
+    
+    /// Instrument de reparatie: recalculeaza contorul unui utilizator din setul sau de proof_id-uri.
+    #[only_owner]
+    #[endpoint(reindexUser)]
+    fn reindex_user(&self, user: ManagedAddress) {
+        let actual_count = self.user_proof_ids(&user).len() as u64;
+        self.user_proof_count(&user).set(actual_count);
+    }
+    
+    /// Instrument de reparatie: recalculeaza `total_proofs` insumand contoarele tuturor utilizatorilor.
+    /// Se apeleaza paginat pornind de la `from = 0`; returneaza `true` cat timp mai sunt pagini.
+    #[only_owner]
+    #[endpoint(recomputeTotal)]
+    fn recompute_total(&self, from: usize, size: usize) -> bool {
+        if from == 0 {
+            self.recompute_total_acc().clear();
+        }
+        
+        let holders = self.proof_holders();
+        let end = core::cmp::min(from + size, holders.len());
+        
+        let mut page_sum = 0u64;
+        for index in (from + 1)..=end {
+            let holder = holders.get_by_index(index);
+            page_sum += self.user_proof_count(&holder).get();
+        }
+        self.recompute_total_acc().update(|acc| *acc += page_sum);
+        
+        if end < holders.len() {
+            return true;
+        }
+        
+        let total = self.recompute_total_acc().take();
+        self.total_proofs().set(total);
+        false
+    }
+
+    /// Emite un eveniment `proofSnapshot` pentru fiecare dovada din pagina indexului global,
+    /// ca un indexer nou sa porneasca fara reluarea intregului istoric.
+    /// Returneaza `true` cat timp mai sunt pagini.
+    #[only_owner]
+    #[endpoint(emitFullSnapshot)]
+    fn emit_full_snapshot(&self, from: usize, size: usize) -> bool {
+        let proof_ids = self.all_proof_ids();
+        let end = core::cmp::min(from + size, proof_ids.len());
+        
+        for index in (from + 1)..=end {
+            let proof_id = proof_ids.get_by_index(index);
+            let owner = self.proof_owners(&proof_id).get();
+            let proof_data = self.user_proofs(&owner, &proof_id).get();
+            let status = self.compute_proof_status(&proof_data);
+            
+            self.proof_snapshot_event(self.event_version(), &proof_id, &owner, proof_data.timestamp, status);
+        }
+        
+        end < proof_ids.len()
+    }
+
+    #[view(getProof)]
+    fn get_proof(&self, user: &ManagedAddress, proof_id: &ManagedBuffer) -> OptionalValue<ProofView<Self::Api>> {
+        if self.user_proofs(user, proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.user_proofs(user, proof_id).get().into_view())
+        }
+    }
+    
+    /// Returneaza ProofView codificat cu codec-ul standard multiversx_sc (top-encode),
+    /// pentru verificatori off-chain care compara hash-ul cu un angajament ancorat.
+    #[view(getProofEncoded)]
+    fn get_proof_encoded(&self, user: &ManagedAddress, proof_id: &ManagedBuffer) -> OptionalValue<ManagedBuffer> {
+        if self.user_proofs(user, proof_id).is_empty() {
+            return OptionalValue::None;
+        }
+        
+        let proof_data = self.user_proofs(user, proof_id).get().into_view();
+        let mut encoded = ManagedBuffer::new();
+        require!(
+            proof_data.top_encode(&mut encoded).is_ok(),
+            "Failed to encode proof data"
+        );
+        
+        OptionalValue::Some(encoded)
+    }
+    
+    #[view(getProofsForPairs)]
+    fn get_proofs_for_pairs(
+        &self,
+        pairs: MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>>,
+    ) -> MultiValueEncoded<ProofView<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        
+        for pair in pairs.into_iter() {
+            let (user, proof_id) = pair.into_tuple();
+            if self.user_proofs(&user, &proof_id).is_empty() {
+                continue;
+            }
+            result.push(self.user_proofs(&user, &proof_id).get().into_view());
+        }
+        
+        result
+    }
+    
+    #[view(getUserProofs)]
+    fn get_user_proofs(&self, user: &ManagedAddress) -> MultiValueEncoded<ProofView<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        
+        for proof_id in self.user_proof_ids(user).iter() {
+            let proof_data = self.user_proofs(user, &proof_id).get();
+            result.push(proof_data.into_view());
+        }
+        
+        result
+    }
+    
+    /// Dovezile utilizatorului ca (timestamp, proof_id), crescator dupa timestamp
+    /// (egalitatile se rezolva dupa secventa din bloc). Sortarea se face on-chain, deci
+    /// sunt procesate doar primele MAX_TIMELINE_SCAN dovezi din indexul utilizatorului.
+    #[view(getUserProofTimeline)]
+    fn get_user_proof_timeline(
+        &self,
+        user: &ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue2<u64, ManagedBuffer>> {
+        let proof_ids = self.user_proof_ids(user);
+        let scanned = core::cmp::min(proof_ids.len(), MAX_TIMELINE_SCAN);
+        
+        // Sortare prin insertie pe (timestamp, secventa, index in set)
+        let mut entries = [(0u64, 0u32, 0usize); MAX_TIMELINE_SCAN];
+        for index in 1..=scanned {
+            let proof_id = proof_ids.get_by_index(index);
+            let entry = (
+                self.user_proofs(user, &proof_id).get().timestamp,
+                self.proof_sequence(&proof_id).get(),
+                index,
+            );
+            
+            let mut position = index - 1;
+            while position > 0 && entries[position - 1] > entry {
+                entries[position] = entries[position - 1];
+                position -= 1;
+            }
+            entries[position] = entry;
+        }
+        
+        let mut result = MultiValueEncoded::new();
+        let end = core::cmp::min(from.saturating_add(size), scanned);
+        for &(timestamp, _, index) in entries.iter().take(end).skip(from) {
+            result.push((timestamp, proof_ids.get_by_index(index)).into());
+        }
+        
+        result
+    }
+    
+    /// proof_id-urile certificate de utilizator intr-o anumita epoca (cost O(n)).
+    #[view(getUserProofsByEpoch)]
+    fn get_user_proofs_by_epoch(&self, user: &ManagedAddress, epoch: u64) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        
+        for proof_id in self.user_proof_ids(user).iter() {
+            if self.user_proofs(user, &proof_id).get().epoch == epoch {
+                result.push(proof_id);
+            }
+        }
+        
+        result
+    }
+    
+    /// Suma dimensiunilor documentelor pentru primele `MAX_DOCUMENT_SIZE_SCAN` dovezi ale utilizatorului.
+    #[view(getTotalDocumentBytes)]
+    fn get_total_document_bytes(&self, user: &ManagedAddress) -> u64 {
+        let mut total = 0u64;
+        
+        for proof_id in self.user_proof_ids(user).iter().take(MAX_DOCUMENT_SIZE_SCAN) {
+            total += self.user_proofs(user, &proof_id).get().document_size;
+        }
+        
+        total
+    }
+    
+    /// Numarul dovezilor utilizatorului cu timestamp in `[start_ts, end_ts)` (cost O(n)).
+    #[view(getProofCountInRange)]
+    fn get_proof_count_in_range(&self, user: &ManagedAddress, start_ts: u64, end_ts: u64) -> u64 {
+        let mut count = 0u64;
+        
+        for proof_id in self.user_proof_ids(user).iter() {
+            let timestamp = self.user_proofs(user, &proof_id).get().timestamp;
+            if timestamp >= start_ts && timestamp < end_ts {
+                count += 1;
+            }
+        }
+        
+        count
+    }
+    
+    /// Varianta usoara pentru liste: (proof_id, timestamp, category, title), fara proof_text.
+    #[view(getUserProofSummaries)]
+    fn get_user_proof_summaries(
+        &self,
+        user: &ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue4<ManagedBuffer, u64, ManagedBuffer, ManagedBuffer>> {
+        let mut result = MultiValueEncoded::new();
+        let proof_ids = self.user_proof_ids(user);
+        let end = core::cmp::min(from + size, proof_ids.len());
+        
+        for index in (from + 1)..=end {
+            let proof_id = proof_ids.get_by_index(index);
+            let proof_data = self.user_proofs(user, &proof_id).get();
+            result.push((proof_id, proof_data.timestamp, proof_data.category, proof_data.title).into());
+        }
+        
+        result
+    }
+    
+    /// Parcurge toate dovezile utilizatorului (cost O(n) in numarul sau de dovezi);
+    /// `from`/`size` se aplica rezultatelor filtrate.
+    #[view(getUserProofsByCategory)]
+    fn get_user_proofs_by_category(
+        &self,
+        user: &ManagedAddress,
+        category: &ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ProofView<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let mut matched = 0usize;
+        
+        for proof_id in self.user_proof_ids(user).iter() {
+            if result.len() >= size {
+                break;
+            }
+            
+            let proof_data = self.user_proofs(user, &proof_id).get();
+            if &proof_data.category != category {
+                continue;
+            }
+            
+            if matched >= from {
+                result.push(proof_data.into_view());
+            }
+            matched += 1;
+        }
+        
+        result
+    }
+    
+    /// Returneaza proof_id-urile utilizatorului cu scor strict mai mare decat `value / 10^decimals`.
+    #[view(getProofsAboveScore)]
+    fn get_proofs_above_score(&self, user: &ManagedAddress, value: u64, decimals: u8) -> MultiValueEncoded<ManagedBuffer> {
+        require!(decimals <= MAX_SCORE_DECIMALS, "Too many score decimals");
+        let mut result = MultiValueEncoded::new();
+        
+        for proof_id in self.user_proof_ids(user).iter() {
+            let proof_data = self.user_proofs(user, &proof_id).get();
+            if self.score_greater_than(proof_data.score_value, proof_data.score_decimals, value, decimals) {
+                result.push(proof_id);
+            }
+        }
+        
+        result
+    }
+    
+    #[view(getBookmarks)]
+    fn get_bookmarks(&self, user: &ManagedAddress, from: usize, size: usize) -> MultiValueEncoded<ProofView<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let bookmarks = self.user_bookmarks(user);
+        let end = core::cmp::min(from + size, bookmarks.len());
+        
+        for index in (from + 1)..=end {
+            let proof_id = bookmarks.get_by_index(index);
+            
+            // Sarim peste dovezile care nu mai exista
+            if self.proof_owners(&proof_id).is_empty() {
+                continue;
+            }
+            result.push(self.load_proof(&proof_id).into_view());
+        }
+        
+        result
+    }
+    
+    #[view(getCollection)]
+    fn get_collection(&self, collection_id: &ManagedBuffer) -> OptionalValue<ProofCollection<Self::Api>> {
+        if self.collections(collection_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.collections(collection_id).get())
+        }
+    }
+    
+    #[view(getCollectionProofs)]
+    fn get_collection_proofs(&self, collection_id: &ManagedBuffer, from: usize, size: usize) -> MultiValueEncoded<ProofView<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let proof_ids = self.collection_proofs(collection_id);
+        let end = core::cmp::min(from + size, proof_ids.len());
+        
+        for index in (from + 1)..=end {
+            let proof_id = proof_ids.get_by_index(index);
+            if self.proof_owners(&proof_id).is_empty() {
+                continue;
+            }
+            result.push(self.load_proof(&proof_id).into_view());
+        }
+        
+        result
+    }
+    
+    #[view(getProofCollection)]
+    fn get_proof_collection(&self, proof_id: &ManagedBuffer) -> OptionalValue<ManagedBuffer> {
+        if self.proof_collection(proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.proof_collection(proof_id).get())
+        }
+    }
+    
+    #[view(getUserProofIds)]
+    fn get_user_proof_ids(&self, user: &ManagedAddress) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        
+        for proof_id in self.user_proof_ids(user).iter() {
+            result.push(proof_id);
+        }
+        
+        result
+    }
+    
+    #[view(getProofOwner)]
+    fn get_proof_owner(&self, proof_id: &ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        if self.proof_owners(proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.proof_owners(proof_id).get())
+        }
+    }
+    
+    /// Mesajul pe care owner-ul il semneaza off-chain:
+    /// prefix | adresa contractului | owner | nonce (big-endian) | proof_id
+    #[view(buildOwnershipChallenge)]
+    fn build_ownership_challenge(&self, proof_id: &ManagedBuffer, nonce: u64) -> ManagedBuffer {
+        require!(!self.proof_owners(proof_id).is_empty(), "Proof does not exist");
+        
+        let mut challenge = ManagedBuffer::new_from_bytes(OWNERSHIP_CHALLENGE_PREFIX);
+        challenge.append(self.blockchain().get_sc_address().as_managed_buffer());
+        challenge.append(self.proof_owners(proof_id).get().as_managed_buffer());
+        challenge.append_bytes(&nonce.to_be_bytes());
+        challenge.append(proof_id);
+        challenge
+    }
+    
+    /// Verifica semnatura ed25519 a owner-ului curent peste provocare.
+    /// Dovezile inexistente si semnaturile de alta lungime decat 64 de octeti intorc false.
+    /// O semnatura de 64 de octeti care nu verifica NU intoarce false: `verify_ed25519`
+    /// opreste executia cu eroarea VM "invalid signature", deci clientii trebuie sa trateze
+    /// o interogare esuata ca raspuns invalid.
+    #[view(verifyOwnershipResponse)]
+    fn verify_ownership_response(&self, proof_id: &ManagedBuffer, nonce: u64, signature: &ManagedBuffer) -> bool {
+        if self.proof_owners(proof_id).is_empty() || signature.len() != ED25519_SIGNATURE_LEN {
+            return false;
+        }
+        
+        let owner = self.proof_owners(proof_id).get();
+        let challenge = self.build_ownership_challenge(proof_id, nonce);
+        self.crypto().verify_ed25519(owner.as_managed_buffer(), &challenge, signature);
+        true
+    }
+    
+    /// Mesajul de semnat pentru relayCertify: prefix | contract | user | nonce |
+    /// campurile variabile, fiecare precedat de lungimea pe 4 octeti.
+    #[view(getRelayMessage)]
+    fn get_relay_message(
+        &self,
+        user: &ManagedAddress,
+        proof_id: &ManagedBuffer,
+        proof_text: &ManagedBuffer,
+        metadata: &ManagedBuffer,
+    ) -> ManagedBuffer {
+        let mut message = ManagedBuffer::new_from_bytes(RELAY_MESSAGE_PREFIX);
+        message.append(self.blockchain().get_sc_address().as_managed_buffer());
+        message.append(user.as_managed_buffer());
+        message.append_bytes(&self.relay_nonce(user).get().to_be_bytes());
+        
+        for field in [proof_id, proof_text, metadata] {
+            message.append_bytes(&(field.len() as u32).to_be_bytes());
+            message.append(field);
+        }
+        
+        message
+    }
+    
+    #[view(getRelayNonce)]
+    fn get_relay_nonce(&self, user: &ManagedAddress) -> u64 {
+        self.relay_nonce(user).get()
+    }
+    
+    #[view(getUserProofCount)]
+    fn get_user_proof_count(&self, user: &ManagedAddress) -> u64 {
+        self.user_proof_count(user).get()
+    }
+    
+    /// Sugereaza un proof_id determinist: hex(sha256(proof_text || salt)), trunchiat la 32 de caractere
+    #[view(suggestProofId)]
+    fn suggest_proof_id(&self, proof_text: &ManagedBuffer, salt: &ManagedBuffer) -> ManagedBuffer {
+        let mut input = proof_text.clone();
+        input.append(salt);
+        let hash = self.crypto().sha256(&input).to_byte_array();
+        
+        let mut encoded = [0u8; SUGGESTED_ID_LEN];
+        self.encode_hex(&hash[..SUGGESTED_ID_LEN / 2], &mut encoded);
+        ManagedBuffer::new_from_bytes(&encoded)
+    }
+    
+    // Hex lowercase; `encoded` trebuie sa aiba exact dublul lungimii lui `bytes`
+    fn encode_hex(&self, bytes: &[u8], encoded: &mut [u8]) {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        for (index, byte) in bytes.iter().enumerate() {
+            encoded[index * 2] = HEX[(byte >> 4) as usize];
+            encoded[index * 2 + 1] = HEX[(byte & 0x0f) as usize];
+        }
+    }
+    
+    /// 0 = normal, 1 = pauza (mutatii blocate), 2 = mentenanta (doar informativ)
+    #[view(getServiceStatus)]
+    fn get_service_status(&self) -> u8 {
+        if self.paused().get() {
+            1
+        } else if self.maintenance_mode().get() {
+            2
+        } else {
+            0
+        }
+    }
+    
+    #[view(getPauseReason)]
+    fn get_pause_reason(&self) -> ManagedBuffer {
+        self.pause_reason().get()
+    }
+    
+    #[view(isSealed)]
+    fn is_sealed(&self) -> bool {
+        self.sealed().get()
+    }
+    
+    #[view(getSchemaVersion)]
+    fn get_schema_version(&self) -> u32 {
+        self.schema_version().get()
+    }
+    
+    #[view(getTotalProofs)]
+    fn get_total_proofs(&self) -> u64 {
+        self.total_proofs().get()
+    }
+    
+    /// Daca `for_issuer` poate folosi id-ul acum: nu exista (activ, in asteptare sau arhivat),
+    /// are doar caractere sigure (daca restrictia e activa), nu e in cooldown-ul de refolosire
+    /// si nu cade sub un prefix rezervat altui emitent.
+    #[view(isProofIdAvailable)]
+    fn is_proof_id_available(&self, proof_id: &ManagedBuffer, for_issuer: &ManagedAddress) -> bool {
+        self.proof_owners(proof_id).is_empty()
+            && self.pending_proofs(proof_id).is_empty()
+            && self.archived_proofs(proof_id).is_empty()
+            && (!self.enforce_safe_ids().get() || self.is_safe_proof_id(proof_id))
+            && !self.is_in_reuse_cooldown(proof_id)
+            && !self.is_prefix_reserved_for_other(proof_id, for_issuer)
+    }
+    
+    /// Recalculeaza checksum-ul dovezii si il compara cu cel salvat. Dovezile salvate in
+    /// layout-ul initial nu pot fi decodate si fac interogarea sa esueze pana cand sunt
+    /// re-encodate de `migrateProofs`, care le salveaza si checksum-ul.
+    #[view(verifyProofIntegrity)]
+    fn verify_proof_integrity(&self, proof_id: &ManagedBuffer) -> bool {
+        if self.proof_owners(proof_id).is_empty() {
+            return false;
+        }
+        
+        let proof_data = self.load_proof(proof_id);
+        proof_data.checksum == self.compute_proof_checksum(&proof_data)
+    }
+    
+    #[view(proofExists)]
+    fn proof_exists(&self, proof_id: &ManagedBuffer) -> bool {
+        !self.proof_owners(proof_id).is_empty()
+    }
+    
+    /// Doar existenta fiecarui id, fara incarcarea datelor dovezii (mai ieftin decat `verifyBatch`)
+    #[view(checkExistence)]
+    fn check_existence(
+        &self,
+        proof_ids: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, bool>> {
+        let mut result = MultiValueEncoded::new();
+        
+        for proof_id in proof_ids {
+            let exists = self.proof_exists(&proof_id);
+            result.push((proof_id, exists).into());
+        }
+        
+        result
+    }
+    
+    #[view(getReferralCount)]
+    fn get_referral_count(&self, referrer: &ManagedAddress) -> u64 {
+        self.referral_count(referrer).get()
+    }
+    
+    #[view(getProofReferrer)]
+    fn get_proof_referrer(&self, proof_id: &ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        if self.proof_referrer(proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.proof_referrer(proof_id).get())
+        }
+    }
+    
+    #[view(getProofSequence)]
+    fn get_proof_sequence(&self, proof_id: &ManagedBuffer) -> OptionalValue<u32> {
+        if self.proof_owners(proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.proof_sequence(proof_id).get())
+        }
+    }
+    
+    #[view(getAttestationCount)]
+    fn get_attestation_count(&self, proof_id: &ManagedBuffer) -> u32 {
+        let mut count = 0u32;
+        for attester in self.proof_attesters(proof_id).iter() {
+            if !self.is_attestation_expired(proof_id, &attester) {
+                count += 1;
+            }
+        }
+        count
+    }
+    
+    #[view(getFlagCount)]
+    fn get_flag_count(&self, proof_id: &ManagedBuffer) -> u32 {
+        self.proof_flag_count(proof_id).get()
+    }
+    
+    #[view(getProofFlags)]
+    fn get_proof_flags(&self, proof_id: &ManagedBuffer) -> MultiValueEncoded<ProofFlag<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for flag in self.proof_flags(proof_id).iter() {
+            result.push(flag);
+        }
+        result
+    }
+    
+    #[view(getAttestedProofs)]
+    fn get_attested_proofs(&self, attester: &ManagedAddress, from: usize, size: usize) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        let proof_ids = self.attester_proofs(attester);
+        let end = core::cmp::min(from + size, proof_ids.len());
+        
+        for index in (from + 1)..=end {
+            result.push(proof_ids.get_by_index(index));
+        }
+        
+        result
+    }
+    
+    #[view(getProofsByRegion)]
+    fn get_proofs_by_region(&self, region: &ManagedBuffer, from: usize, size: usize) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        let proof_ids = self.proofs_by_region(region);
+        let end = core::cmp::min(from + size, proof_ids.len());
+        
+        for index in (from + 1)..=end {
+            result.push(proof_ids.get_by_index(index));
+        }
+        
+        result
+    }
+    
+    #[view(getRegionCount)]
+    fn get_region_count(&self, region: &ManagedBuffer) -> usize {
+        self.proofs_by_region(region).len()
+    }
+    
+    #[view(getTopProofs)]
+    fn get_top_proofs(&self, n: usize) -> MultiValueEncoded<MultiValue2<ManagedBuffer, u32>> {
+        let mut result = MultiValueEncoded::new();
+        let top = self.top_proofs();
+        let end = core::cmp::min(n, top.len());
+        
+        for index in 1..=end {
+            let ranked = top.get(index);
+            result.push((ranked.proof_id, ranked.attestations).into());
+        }
+        
+        result
+    }
+    
+    // Dovada exista, nu e revocata sau expirata, a inceput sa fie valida si are suficiente atestari
+    #[view(isProofActive)]
+    fn is_proof_active(&self, proof_id: &ManagedBuffer) -> bool {
+        if !self.is_proof_valid(proof_id) {
+            return false;
+        }
+        
+        self.get_attestation_count(proof_id) >= self.attestation_threshold().get()
+            && self.get_weighted_attestation_score(proof_id) >= self.weighted_attestation_threshold().get()
+    }
+    
+    /// Starea cea mai semnificativa a dovezii, in ordinea de precedenta:
+    /// Revoked > Escrowed > Expired > NotYetValid > Frozen > Sealed > Active.
+    #[view(getProofStatus)]
+    fn get_proof_status(&self, proof_id: &ManagedBuffer) -> OptionalValue<ProofStatus> {
+        if self.proof_owners(proof_id).is_empty() {
+            return OptionalValue::None;
+        }
+        
+        let proof_data = self.load_proof(proof_id);
+        OptionalValue::Some(self.compute_proof_status(&proof_data))
+    }
+    
+    /// ProofView, owner-ul, starea si numarul de atestari active ale dovezii,
+    /// intr-un singur apel pentru pagina de detaliu.
+    #[view(getProofContext)]
+    fn get_proof_context(
+        &self,
+        proof_id: &ManagedBuffer,
+    ) -> OptionalValue<MultiValue4<ProofView<Self::Api>, ManagedAddress, ProofStatus, u32>> {
+        if self.proof_owners(proof_id).is_empty() {
+            return OptionalValue::None;
+        }
+        
+        let owner = self.proof_owners(proof_id).get();
+        let proof_data = self.user_proofs(&owner, proof_id).get();
+        let status = self.compute_proof_status(&proof_data);
+        let attestations = self.get_attestation_count(proof_id);
+        
+        OptionalValue::Some((proof_data.into_view(), owner, status, attestations).into())
+    }
+    
+    fn compute_proof_status(&self, proof_data: &ProofData<Self::Api>) -> ProofStatus {
+        if proof_data.is_revoked() {
+            ProofStatus::Revoked
+        } else if !self.escrow_condition_hash(&proof_data.proof_id).is_empty() {
+            ProofStatus::Escrowed
+        } else if self.is_expired(proof_data) {
+            ProofStatus::Expired
+        } else if self.is_not_yet_valid(proof_data) {
+            ProofStatus::NotYetValid
+        } else if proof_data.is_frozen() {
+            ProofStatus::Frozen
+        } else if proof_data.is_immutable() {
+            ProofStatus::Sealed
+        } else {
+            ProofStatus::Active
+        }
+    }
+    
+    /// Pentru fiecare dovada ceruta, aceeasi regula ca `isProofActive`: exista, nu e revocata,
+    /// nu a expirat, a ajuns la `valid_from`, nu e in escrow si atinge pragurile de atestare.
+    #[view(verifyBatch)]
+    fn verify_batch(
+        &self,
+        proof_ids: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, bool>> {
+        let mut result = MultiValueEncoded::new();
+        
+        for proof_id in proof_ids {
+            let active = self.is_proof_active(&proof_id);
+            result.push((proof_id, active).into());
+        }
+        
+        result
+    }
+    
+    fn is_proof_valid(&self, proof_id: &ManagedBuffer) -> bool {
+        if self.proof_owners(proof_id).is_empty() {
+            return false;
+        }
+        
+        let proof_data = self.load_proof(proof_id);
+        !proof_data.is_revoked()
+            && !self.is_expired(&proof_data)
+            && !self.is_not_yet_valid(&proof_data)
+            && self.escrow_condition_hash(proof_id).is_empty()
+    }
+    
+    /// Suma ponderilor atestatorilor dovezii cu atestari neexpirate
+    #[view(getWeightedAttestationScore)]
+    fn get_weighted_attestation_score(&self, proof_id: &ManagedBuffer) -> u64 {
+        let mut score = 0u64;
+        for attester in self.proof_attesters(proof_id).iter() {
+            if !self.is_attestation_expired(proof_id, &attester) {
+                score += self.get_attester_weight(&attester);
+            }
+        }
+        score
+    }
+    
+    #[view(getAttesterWeight)]
+    fn get_attester_weight(&self, attester: &ManagedAddress) -> u64 {
+        if self.attester_weight(attester).is_empty() {
+            1
+        } else {
+            self.attester_weight(attester).get()
+        }
+    }
+    
+    #[view(getProofAgeSeconds)]
+    fn get_proof_age_seconds(&self, proof_id: &ManagedBuffer) -> OptionalValue<u64> {
+        if self.proof_owners(proof_id).is_empty() {
+            return OptionalValue::None;
+        }
+        
+        let proof_data = self.load_proof(proof_id);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        
+        // Timestamp din viitor nu ar trebui sa apara, dar nu facem underflow
+        OptionalValue::Some(current_timestamp.saturating_sub(proof_data.timestamp))
+    }
+    
+    /// Copiii directi ai unei dovezi, fiecare cu flag-ul parent_revoked
+    #[view(getChildrenRevocationStatus)]
+    fn get_children_revocation_status(
+        &self,
+        parent_id: &ManagedBuffer,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, bool>> {
+        let mut result = MultiValueEncoded::new();
+        for child_id in self.proof_children(parent_id).iter() {
+            let flagged = self.parent_revoked(&child_id).get();
+            result.push((child_id, flagged).into());
+        }
+        result
+    }
+    
+    #[view(getArchivedProof)]
+    fn get_archived_proof(&self, proof_id: &ManagedBuffer) -> OptionalValue<ArchivedProof<Self::Api>> {
+        if self.archived_proofs(proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.archived_proofs(proof_id).get())
+        }
+    }
+    
+    #[view(getSupersessionChain)]
+    fn get_supersession_chain(&self, proof_id: &ManagedBuffer) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        if self.proof_owners(proof_id).is_empty() {
+            return result;
+        }
+        
+        // Ultimul element este versiunea cea mai recenta
+        let mut current_id = proof_id.clone();
+        loop {
+            let proof_data = self.load_proof(&current_id);
+            result.push(current_id);
+            
+            if proof_data.superseded_by.is_empty() || self.proof_owners(&proof_data.superseded_by).is_empty() {
+                break;
+            }
+            current_id = proof_data.superseded_by;
+        }
+        
+        result
+    }
+    
+    /// Urmeaza pointerii de contopire pana la id-ul canonic; un id necontopit se intoarce neschimbat.
+    #[view(resolveProofId)]
+    fn resolve_proof_id(&self, proof_id: &ManagedBuffer) -> ManagedBuffer {
+        let mut current_id = proof_id.clone();
+        while !self.merged_into(&current_id).is_empty() {
+            current_id = self.merged_into(&current_id).get();
+        }
+        
+        current_id
+    }
+    
+    #[view(getPendingTransfer)]
+    fn get_pending_transfer(&self, proof_id: &ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        if self.pending_transfer(proof_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.pending_transfer(proof_id).get())
+        }
+    }
+    
+    /// Pentru butonul de editare din UI: apelantul este owner, co-owner sau editor delegat,
+    /// contractul nu e in pauza sau append-only, iar dovada nu este inghetata, revocata sau imuabila.
+    #[view(canEditProof)]
+    fn can_edit_proof(&self, proof_id: &ManagedBuffer, editor: &ManagedAddress) -> bool {
+        if self.proof_owners(proof_id).is_empty() || self.paused().get() || self.append_only().get() {
+            return false;
+        }
+        
+        let owner = self.proof_owners(proof_id).get();
+        if !self.has_edit_rights(proof_id, &owner, editor) {
+            return false;
+        }
+        
+        let proof_data = self.user_proofs(&owner, proof_id).get();
+        self.edit_denial_reason(&proof_data).is_none()
+    }
+    
+    /// Dovezile private pot fi citite doar de owner si de adresele din ACL-ul `proofReaders`.
+    /// Storage-ul ramane vizibil on-chain; front-end-urile si indexerii aplica aceasta regula.
+    #[view(canReadProof)]
+    fn can_read_proof(&self, proof_id: &ManagedBuffer, reader: &ManagedAddress) -> bool {
+        if self.proof_owners(proof_id).is_empty() {
+            return false;
+        }
+        
+        let owner = self.proof_owners(proof_id).get();
+        if !self.user_proofs(&owner, proof_id).get().is_private() {
+            return true;
+        }
+        
+        &owner == reader || self.proof_readers(proof_id).contains(reader)
+    }
+    
+    /// Daca adresa poate certifica acum: aceleasi reguli ca endpoint-urile de certificare
+    /// (pauza, sigilare, restrictia la apelanti contracte, whitelist, NFT de acces,
+    /// dovada prealabila, sold minim), pentru o dovada din afara categoriei prealabile.
+    /// `gating_nonce` este NFT-ul din colectia de acces pe care adresa l-ar indica la certificare.
+    #[view(getEffectiveIssuerPermission)]
+    fn get_effective_issuer_permission(&self, address: &ManagedAddress, gating_nonce: OptionalValue<u64>) -> bool {
+        if self.paused().get() || self.sealed().get() {
+            return false;
+        }
+        
+        self.certify_denial_reason(address, address, &ManagedBuffer::new(), gating_nonce.into_option().unwrap_or_default()).is_none()
+    }
+    
+    #[view(getViewCount)]
+    fn get_view_count(&self, proof_id: &ManagedBuffer) -> u64 {
+        self.proof_view_count(proof_id).get()
+    }
+    
+    #[view(getDefaultPrivate)]
+    fn get_default_private(&self) -> bool {
+        self.default_private().get()
+    }
+    
+    /// Owner-ul principal urmat de co-owneri, fara duplicate; gol pentru dovezi inexistente.
+    #[view(getAllProofOwners)]
+    fn get_all_proof_owners(&self, proof_id: &ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        if self.proof_owners(proof_id).is_empty() {
+            return result;
+        }
+        
+        let owner = self.proof_owners(proof_id).get();
+        result.push(owner.clone());
+        
+        // Setul de co-owneri e deja unic; owner-ul nu poate fi adaugat ca co-owner
+        for co_owner in self.proof_co_owners(proof_id).iter() {
+            if co_owner != owner {
+                result.push(co_owner);
+            }
+        }
+        
+        result
+    }
+    
+    #[view(getEditors)]
+    fn get_editors(&self, proof_id: &ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
         
-        // Adaugă proof_id în lista utilizatorului
-        self.user_proof_ids(&caller).insert(proof_id.clone());
+        for editor in self.proof_editors(proof_id).iter() {
+            result.push(editor);
+        }
         
-        // Mapează proof_id la owner
-        self.proof_owners(&proof_id).set(caller.clone());
+        result
+    }
+    
+    #[view(getProofIssuer)]
+    fn get_proof_issuer(&self, proof_id: &ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        if self.proof_owners(proof_id).is_empty() {
+            return OptionalValue::None;
+        }
         
-        // Incrementează contoarele
-        let current_count = self.user_proof_count(&caller).get();
-        self.user_proof_count(&caller).set(current_count + 1);
+        OptionalValue::Some(self.load_proof(proof_id).issuer)
+    }
+    
+    #[view(getCompressionInfo)]
+    fn get_compression_info(&self, proof_id: &ManagedBuffer) -> OptionalValue<MultiValue2<bool, ManagedBuffer>> {
+        if self.proof_owners(proof_id).is_empty() {
+            return OptionalValue::None;
+        }
         
-        let total = self.total_proofs().get();
-        self.total_proofs().set(total + 1);
+        let proof_data = self.load_proof(proof_id);
         
-        // Emit event
-        self.proof_certified_event(&caller, &proof_id, &proof_text, current_timestamp);
+        OptionalValue::Some((proof_data.compressed, proof_data.compression_algo).into())
     }
     
-    #[endpoint]
-    fn update_proof(&self, proof_id: ManagedBuffer, new_proof_text: ManagedBuffer, new_metadata: OptionalValue<ManagedBuffer>) {
-        let caller = self.blockchain().get_caller();
+    #[view(getEncryptionInfo)]
+    fn get_encryption_info(&self, proof_id: &ManagedBuffer) -> OptionalValue<MultiValue2<bool, ManagedBuffer>> {
+        if self.proof_owners(proof_id).is_empty() {
+            return OptionalValue::None;
+        }
         
-        // Verifică ownership
-        let owner = self.proof_owners(&proof_id).get();
-        require!(
-            owner == caller,
-            "Only proof owner can update"
-        );
+        let proof_data = self.load_proof(proof_id);
         
-        require!(
-            new_proof_text.len() > 0 && new_proof_text.len() <= 500,
-            "Proof text must be between 1 and 500 characters"
-        );
+        OptionalValue::Some((proof_data.is_encrypted(), proof_data.encryption_scheme).into())
+    }
+    
+    /// Numarul de dovezi pentru fiecare categorie ceruta (0 pentru cele necunoscute).
+    #[view(getCategoryBreakdown)]
+    fn get_category_breakdown(
+        &self,
+        categories: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, u64>> {
+        let mut result = MultiValueEncoded::new();
         
-        let mut proof_data = self.user_proofs(&caller, &proof_id).get();
-        proof_data.proof_text = new_proof_text.clone();
+        for category in categories {
+            let count = self.category_count(&category).get();
+            result.push((category, count).into());
+        }
         
-        if let OptionalValue::Some(metadata) = new_metadata {
-            proof_data.metadata = metadata;
+        result
+    }
+    
+    #[view(getUserCategoryCount)]
+    fn get_user_category_count(&self, user: &ManagedAddress, category: &ManagedBuffer) -> u64 {
+        self.user_category_count(user, category).get()
+    }
+    
+    #[view(getRequiredMetadataPrefix)]
+    fn get_required_metadata_prefix(&self) -> ManagedBuffer {
+        self.required_metadata_prefix().get()
+    }
+    
+    #[view(getPrefixCategoryRules)]
+    fn get_prefix_category_rules(&self) -> MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>> {
+        let mut result = MultiValueEncoded::new();
+        
+        for (prefix, category) in self.prefix_category_rules().iter() {
+            result.push((prefix, category).into());
         }
         
-        self.user_proofs(&caller, &proof_id).set(proof_data);
+        result
+    }
+    
+    #[view(getAllowedCategories)]
+    fn get_allowed_categories(&self) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
         
-        // Emit update event
-        self.proof_updated_event(&caller, &proof_id, &new_proof_text);
+        for category in self.allowed_categories().iter() {
+            result.push(category);
+        }
+        
+        result
     }
-
-    #[view(getProof)]
-    fn get_proof(&self, user: &ManagedAddress, proof_id: &ManagedBuffer) -> OptionalValue<ProofData<Self::Api>> {
-        if self.user_proofs(user, proof_id).is_empty() {
+    
+    #[view(findProofByHash)]
+    fn find_proof_by_hash(&self, content_hash: &ManagedBuffer) -> OptionalValue<ManagedBuffer> {
+        if self.proof_by_content_hash(content_hash).is_empty() {
             OptionalValue::None
         } else {
-            OptionalValue::Some(self.user_proofs(user, proof_id).get())
+            OptionalValue::Some(self.proof_by_content_hash(content_hash).get())
         }
     }
     
-    #[view(getUserProofs)]
-    fn get_user_proofs(&self, user: &ManagedAddress) -> MultiValueEncoded<ProofData<Self::Api>> {
-        let mut result = MultiValueEncoded::new();
-        
-        for proof_id in self.user_proof_ids(user).iter() {
-            let proof_data = self.user_proofs(user, &proof_id).get();
-            result.push(proof_data);
+    #[view(getTotalFeesCollected)]
+    fn get_total_fees_collected(&self) -> BigUint {
+        self.total_fees_collected().get()
+    }
+    
+    #[view(getRateLimit)]
+    fn get_rate_limit(&self) -> MultiValue2<u64, u64> {
+        (self.rate_limit_max().get(), self.rate_limit_window().get()).into()
+    }
+    
+    #[view(getCertifyCooldown)]
+    fn get_certify_cooldown(&self) -> u64 {
+        self.certify_cooldown().get()
+    }
+    
+    #[view(getFeeReserve)]
+    fn get_fee_reserve(&self) -> BigUint {
+        self.fee_reserve().get()
+    }
+    
+    /// Limitele efective (min, max) pentru emitent; globale daca nu are override
+    #[view(getIssuerTextLimit)]
+    fn get_issuer_text_limit(&self, issuer: &ManagedAddress) -> MultiValue2<u32, u32> {
+        if self.issuer_text_limit(issuer).is_empty() {
+            self.global_text_bounds().into()
+        } else {
+            self.issuer_text_limit(issuer).get().into()
         }
+    }
+    
+    /// (rezerva de taxe, recuperabil) pentru un token, unde recuperabil este soldul
+    /// on-chain al contractului minus rezerva urmarita si pool-ul de recompense.
+    #[view(getBalanceBreakdown)]
+    fn get_balance_breakdown(&self, token: EgldOrEsdtTokenIdentifier) -> MultiValue2<BigUint, BigUint> {
+        let reserve = self.tracked_fee_balance(&token).get();
+        let mut locked = reserve.clone();
+        if !self.reward_token().is_empty() && token == EgldOrEsdtTokenIdentifier::esdt(self.reward_token().get()) {
+            locked += self.reward_pool().get();
+        }
+        
+        let balance = self.blockchain().get_sc_balance(&token, 0);
+        let rescuable = if balance > locked {
+            balance - &locked
+        } else {
+            BigUint::zero()
+        };
         
+        (reserve, rescuable).into()
+    }
+    
+    #[view(getFeeBeneficiaries)]
+    fn get_fee_beneficiaries(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u32>> {
+        let mut result = MultiValueEncoded::new();
+        for beneficiary in self.fee_beneficiaries().iter() {
+            result.push((beneficiary.address, beneficiary.bps).into());
+        }
         result
     }
     
-    #[view(getUserProofIds)]
-    fn get_user_proof_ids(&self, user: &ManagedAddress) -> MultiValueEncoded<ManagedBuffer> {
+    #[view(getRewardPool)]
+    fn get_reward_pool(&self) -> BigUint {
+        self.reward_pool().get()
+    }
+    
+    #[view(getTransferFee)]
+    fn get_transfer_fee(&self) -> BigUint {
+        self.transfer_fee().get()
+    }
+    
+    #[view(getTotalFeesInUnit)]
+    fn get_total_fees_in_unit(&self) -> BigUint {
+        self.total_fees_in_unit().get()
+    }
+    
+    #[view(getFeeTokenRate)]
+    fn get_fee_token_rate(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.fee_token_rate(token).get()
+    }
+    
+    #[view(getTokenFeeReserve)]
+    fn get_token_fee_reserve(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.tracked_fee_balance(token).get()
+    }
+    
+    /// Pagina de (emitent, numar de dovezi existente emise) din setul `knownIssuers`.
+    #[view(getIssuersPaged)]
+    fn get_issuers_paged(&self, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
         let mut result = MultiValueEncoded::new();
+        let issuers = self.known_issuers();
+        let end = core::cmp::min(from + size, issuers.len());
         
-        for proof_id in self.user_proof_ids(user).iter() {
-            result.push(proof_id);
+        for index in (from + 1)..=end {
+            let issuer = issuers.get_by_index(index);
+            let count = self.issuer_proof_count(&issuer).get();
+            result.push((issuer, count).into());
         }
         
         result
     }
     
-    #[view(getProofOwner)]
-    fn get_proof_owner(&self, proof_id: &ManagedBuffer) -> OptionalValue<ManagedAddress> {
-        if self.proof_owners(proof_id).is_empty() {
+    #[view(getIssuerReputation)]
+    fn get_issuer_reputation(&self, issuer: &ManagedAddress) -> u64 {
+        self.issuer_reputation(issuer).get()
+    }
+    
+    /// Taxa pe care o plateste emitentul dupa aplicarea reducerii de reputatie
+    #[view(getEffectiveCertificationFee)]
+    fn get_effective_certification_fee(&self, issuer: &ManagedAddress) -> BigUint {
+        self.effective_certification_fee(issuer, &ManagedBuffer::new())
+    }
+    
+    #[view(getProofListener)]
+    fn get_proof_listener(&self) -> OptionalValue<ManagedAddress> {
+        if self.proof_listener().is_empty() {
             OptionalValue::None
         } else {
-            OptionalValue::Some(self.proof_owners(proof_id).get())
+            OptionalValue::Some(self.proof_listener().get())
         }
     }
     
-    #[view(getUserProofCount)]
-    fn get_user_proof_count(&self, user: &ManagedAddress) -> u64 {
-        self.user_proof_count(user).get()
+    #[view(getMinCertifierBalance)]
+    fn get_min_certifier_balance(&self) -> BigUint {
+        self.min_certifier_balance().get()
     }
     
-    #[view(getTotalProofs)]
-    fn get_total_proofs(&self) -> u64 {
-        self.total_proofs().get()
+    #[view(getPendingApprovals)]
+    fn get_pending_approvals(&self, proof_id: &ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        
+        for approver in self.proof_approvals(proof_id).iter() {
+            result.push(approver);
+        }
+        
+        result
     }
     
-    #[view(proofExists)]
-    fn proof_exists(&self, proof_id: &ManagedBuffer) -> bool {
-        !self.proof_owners(proof_id).is_empty()
+    #[view(getAuthorizedIssuers)]
+    fn get_authorized_issuers(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        
+        for issuer in self.authorized_issuers().iter() {
+            result.push(issuer);
+        }
+        
+        result
     }
 
     // Events
     #[event("proofCertified")]
     fn proof_certified_event(
         &self,
+        #[indexed] schema_version: u32,
         #[indexed] user: &ManagedAddress,
         #[indexed] proof_id: &ManagedBuffer,
         proof_text: &ManagedBuffer,
@@ -185,8 +4382,297 @@ pub trait OnChainProof {
     #[event("proofUpdated")]
     fn proof_updated_event(
         &self,
+        #[indexed] schema_version: u32,
         #[indexed] user: &ManagedAddress,
         #[indexed] proof_id: &ManagedBuffer,
         new_proof_text: &ManagedBuffer,
     );
-}
\ No newline at end of file
+    
+    #[event("proofUpdatedDetailed")]
+    fn proof_updated_detailed_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+        diff: &ProofTextDiff<Self::Api>,
+    );
+    
+    #[event("proofProposed")]
+    fn proof_proposed_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proposer: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+        required_sigs: u32,
+    );
+    
+    #[event("proofApproved")]
+    fn proof_approved_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] approver: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofIdGenerated")]
+    fn proof_id_generated_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofTransferred")]
+    fn proof_transferred_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofRevoked")]
+    fn proof_revoked_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] revoked_by: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofSuperseded")]
+    fn proof_superseded_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] old_id: &ManagedBuffer,
+        #[indexed] new_id: &ManagedBuffer,
+    );
+    
+    #[event("collectionCreated")]
+    fn collection_created_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] collection_id: &ManagedBuffer,
+    );
+    
+    #[event("proofAddedToCollection")]
+    fn proof_added_to_collection_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] collection_id: &ManagedBuffer,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofSwept")]
+    fn proof_swept_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] swept_by: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("editorGranted")]
+    fn editor_granted_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+        #[indexed] editor: &ManagedAddress,
+    );
+    
+    #[event("editorRevoked")]
+    fn editor_revoked_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+        #[indexed] editor: &ManagedAddress,
+    );
+    
+    #[event("proofAttested")]
+    fn proof_attested_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] attester: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("transferOffered")]
+    fn transfer_offered_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("transferCancelled")]
+    fn transfer_cancelled_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("bulkFrozen")]
+    fn bulk_frozen_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] user: &ManagedAddress,
+        count: u32,
+    );
+    
+    #[event("bulkUnfrozen")]
+    fn bulk_unfrozen_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] user: &ManagedAddress,
+        count: u32,
+    );
+    
+    #[event("parentLinked")]
+    fn parent_linked_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] parent_id: &ManagedBuffer,
+        #[indexed] child_id: &ManagedBuffer,
+    );
+    
+    #[event("parentRevocationPropagated")]
+    fn parent_revocation_propagated_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] parent_id: &ManagedBuffer,
+        flagged_children: u32,
+    );
+    
+    #[event("feesWithdrawn")]
+    fn fees_withdrawn_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+    
+    #[event("proofFlagged")]
+    fn proof_flagged_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] flagger: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("flagResolved")]
+    fn flag_resolved_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+        upheld: bool,
+    );
+    
+    #[event("contractSealed")]
+    fn contract_sealed_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] timestamp: u64,
+    );
+    
+    #[event("proofArchived")]
+    fn proof_archived_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofPurged")]
+    fn proof_purged_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("metadataUpdated")]
+    fn metadata_updated_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("readerGranted")]
+    fn reader_granted_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+        #[indexed] reader: &ManagedAddress,
+    );
+    
+    #[event("readerRevoked")]
+    fn reader_revoked_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+        #[indexed] reader: &ManagedAddress,
+    );
+    
+    #[event("proofImported")]
+    fn proof_imported_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofRenamed")]
+    fn proof_renamed_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] old_id: &ManagedBuffer,
+        #[indexed] new_id: &ManagedBuffer,
+    );
+    
+    #[event("proofSnapshot")]
+    fn proof_snapshot_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] proof_id: &ManagedBuffer,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] timestamp: u64,
+        status: ProofStatus,
+    );
+    
+    #[event("proofMerged")]
+    fn proof_merged_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] keep_id: &ManagedBuffer,
+        #[indexed] merge_id: &ManagedBuffer,
+    );
+    
+    #[event("proofEscrowed")]
+    fn proof_escrowed_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] issuer: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("proofReleased")]
+    fn proof_released_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] releaser: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+    
+    #[event("titleUpdated")]
+    fn title_updated_event(
+        &self,
+        #[indexed] schema_version: u32,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] proof_id: &ManagedBuffer,
+    );
+}
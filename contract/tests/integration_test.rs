@@ -254,7 +254,7 @@ fn update_proof_test() {
                     ManagedBuffer::from(b"Malicious Update"),
                     OptionalValue::None,
                 ))
-                .expect(TxExpect::user_error("str:Only proof owner can update")),
+                .expect(TxExpect::user_error("str:Only proof owner or editor can update")),
         );
 }
 
@@ -1,8 +1,34 @@
+use multiversx_sc::codec::{TopDecode, TopEncode};
+use multiversx_sc::types::Address;
 use multiversx_sc_scenario::*;
 use onchain_proof::*;
 
 const CONTRACT_WASM_PATH: &str = "output/onchain-proof.wasm";
 
+type ProofContract = ContractObjWrapper<
+    onchain_proof::ContractObj<DebugApi>,
+    fn() -> onchain_proof::ContractObj<DebugApi>,
+>;
+
+fn setup() -> (BlockchainStateWrapper, Address, ProofContract) {
+    let mut blockchain = BlockchainStateWrapper::new();
+    let owner = blockchain.create_user_account(&rust_biguint!(0));
+    let contract_wrapper = blockchain.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        onchain_proof::contract_obj as fn() -> onchain_proof::ContractObj<DebugApi>,
+        CONTRACT_WASM_PATH,
+    );
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    (blockchain, owner, contract_wrapper)
+}
+
 #[test]
 fn test_full_proof_lifecycle() {
     let mut blockchain = BlockchainStateWrapper::new();
@@ -119,7 +145,7 @@ fn test_multiple_users_scenario() {
             sc.certify_action(
                 managed_buffer!(b"BOB_CERTIFICATE"),
                 managed_buffer!(b"bob_cert_1"),
-                OptionalValue::Some(managed_buffer!(b"{\"grade\": \"A+\"}")))
+                OptionalValue::Some(managed_buffer!(b"{\"grade\": \"A+\"}")),
             );
         })
         .assert_ok();
@@ -196,4 +222,6572 @@ fn test_proof_verification_workflow() {
             assert!(proof_data.timestamp > 0);
         })
         .assert_ok();
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_quorum_proof_finalization() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let proposer = blockchain.create_user_account(&rust_biguint!(0));
+    let issuers = [
+        blockchain.create_user_account(&rust_biguint!(0)),
+        blockchain.create_user_account(&rust_biguint!(0)),
+        blockchain.create_user_account(&rust_biguint!(0)),
+    ];
+
+    for issuer in issuers.iter() {
+        blockchain
+            .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.add_authorized_issuer(managed_address!(issuer));
+            })
+            .assert_ok();
+    }
+
+    let proof_id = managed_buffer!(b"license_001");
+
+    // Propunere 2-of-3
+    blockchain
+        .execute_tx(&proposer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"license_001"),
+                managed_buffer!(b"MEDICAL_LICENSE"),
+                managed_buffer!(b"{\"board\": \"national\"}"),
+                2,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuers[0], &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"license_001"));
+        })
+        .assert_ok();
+
+    // Aprobarea duplicata este respinsa
+    blockchain
+        .execute_tx(&issuers[0], &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"license_001"));
+        })
+        .assert_user_error("Proof already approved by caller");
+
+    // Un singur vot - dovada este inca in asteptare
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.proof_exists(&proof_id));
+            assert_eq!(sc.get_pending_approvals(&proof_id).len(), 1);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&proposer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"license_001"));
+        })
+        .assert_user_error("Only authorized issuers can approve");
+
+    blockchain
+        .execute_tx(&issuers[1], &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"license_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.proof_exists(&proof_id));
+            assert_eq!(sc.get_pending_approvals(&proof_id).len(), 0);
+
+            let owner = sc.get_proof_owner(&proof_id).into_option().unwrap();
+            assert_eq!(owner, managed_address!(&proposer));
+            assert_eq!(sc.get_total_proofs(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_quorum_proof_schema_rules() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let proposer = blockchain.create_user_account(&rust_biguint!(0));
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_authorized_issuer(managed_address!(&issuer));
+            sc.set_required_metadata_prefix(managed_buffer!(b"schema:v1"));
+            sc.set_certify_cooldown(100);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&proposer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"quorum_schema_001"),
+                managed_buffer!(b"MEDICAL_LICENSE"),
+                managed_buffer!(b"{\"board\": \"national\"}"),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Metadata schema mismatch");
+
+    blockchain
+        .execute_tx(&proposer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"quorum_schema_001"),
+                managed_buffer!(b"MEDICAL_LICENSE"),
+                managed_buffer!(b"schema:v1{\"board\": \"national\"}"),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Cooldown-ul de certificare se aplica si propunerilor
+    blockchain
+        .execute_tx(&proposer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"quorum_schema_002"),
+                managed_buffer!(b"MEDICAL_LICENSE"),
+                managed_buffer!(b"schema:v1{}"),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Certify cooldown active");
+
+    // Schema s-a schimbat inainte de cvorum - propunerea nu mai poate fi finalizata
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_required_metadata_prefix(managed_buffer!(b"schema:v2"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"quorum_schema_001"));
+        })
+        .assert_user_error("Metadata schema mismatch");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.proof_exists(&managed_buffer!(b"quorum_schema_001")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_certify_auto_unique_ids_in_same_block() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_nonce(42);
+
+    for _ in 0..2 {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_auto(managed_buffer!(b"AUTO_BADGE"), OptionalValue::None, OptionalValue::None);
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let ids: Vec<ManagedBuffer<DebugApi>> = sc
+                .get_user_proof_ids(&managed_address!(&user))
+                .to_vec()
+                .into_iter()
+                .collect();
+            assert_eq!(ids.len(), 2);
+            assert_ne!(ids[0], ids[1]);
+
+            for proof_id in ids.iter() {
+                let owner = sc.get_proof_owner(proof_id).into_option().unwrap();
+                assert_eq!(owner, managed_address!(&user));
+
+                // Id-ul generat este hex printabil, utilizabil direct in URL-uri
+                let id_bytes = proof_id.to_boxed_bytes();
+                assert_eq!(id_bytes.len(), AUTO_ID_RAW_LEN * 2);
+                assert!(id_bytes
+                    .as_slice()
+                    .iter()
+                    .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(byte)));
+            }
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&user)), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proof_age_seconds() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_timestamp(1_000);
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"AGED_CERTIFICATE"),
+                managed_buffer!(b"aged_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_000 + 3 * 86_400);
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let age = sc.get_proof_age_seconds(&managed_buffer!(b"aged_001")).into_option().unwrap();
+            assert_eq!(age, 3 * 86_400);
+
+            assert!(sc.get_proof_age_seconds(&managed_buffer!(b"missing")).into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_encrypted_proof_info() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"3f9a1c0b7e2d...ciphertext"),
+                managed_buffer!(b"confidential_001"),
+                CertifyOptions {
+                    encryption_scheme: managed_buffer!(b"AES-256-GCM"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PLAIN_CERTIFICATE"),
+                managed_buffer!(b"plain_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let (encrypted, scheme) = sc
+                .get_encryption_info(&managed_buffer!(b"confidential_001"))
+                .into_option()
+                .unwrap()
+                .into_tuple();
+            assert!(encrypted);
+            assert_eq!(scheme, managed_buffer!(b"AES-256-GCM"));
+
+            let (encrypted, scheme) = sc
+                .get_encryption_info(&managed_buffer!(b"plain_001"))
+                .into_option()
+                .unwrap()
+                .into_tuple();
+            assert!(!encrypted);
+            assert!(scheme.is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_issuer_can_revoke_after_transfer() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let holder = blockchain.create_user_account(&rust_biguint!(0));
+    let stranger = blockchain.create_user_account(&rust_biguint!(0));
+    let proof_id = managed_buffer!(b"regulated_001");
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"REGULATED_LICENSE"),
+                managed_buffer!(b"regulated_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"regulated_001"), managed_address!(&holder));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let owner = sc.get_proof_owner(&proof_id).into_option().unwrap();
+            assert_eq!(owner, managed_address!(&holder));
+
+            let proof_issuer = sc.get_proof_issuer(&proof_id).into_option().unwrap();
+            assert_eq!(proof_issuer, managed_address!(&issuer));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&stranger, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_proof(managed_buffer!(b"regulated_001"));
+        })
+        .assert_user_error("Only proof owner or issuer can revoke");
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_proof(managed_buffer!(b"regulated_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof_data = sc.get_proof(&managed_address!(&holder), &proof_id).into_option().unwrap();
+            assert!(proof_data.revoked);
+            assert_eq!(proof_data.issuer, managed_address!(&issuer));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_min_certifier_balance() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let poor_user = blockchain.create_user_account(&rust_biguint!(50));
+    let rich_user = blockchain.create_user_account(&rust_biguint!(150));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_certifier_balance(managed_biguint!(100));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&poor_user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"SPAM_PROOF"),
+                managed_buffer!(b"spam_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Insufficient balance to certify");
+
+    blockchain
+        .execute_tx(&rich_user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"LEGIT_PROOF"),
+                managed_buffer!(b"legit_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Un minim de zero dezactiveaza verificarea
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_certifier_balance(managed_biguint!(0));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&poor_user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"SMALL_PROOF"),
+                managed_buffer!(b"small_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_supersession_chain() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    for version in 1..=3 {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(format!("CREDENTIAL_V{}", version).as_bytes()),
+                    managed_buffer!(format!("credential_v{}", version).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.supersede_proof(managed_buffer!(b"credential_v1"), managed_buffer!(b"credential_v2"), true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.supersede_proof(managed_buffer!(b"credential_v2"), managed_buffer!(b"credential_v3"), false);
+        })
+        .assert_ok();
+
+    // v3 nu poate fi legat inapoi la v1 - ar crea un ciclu
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.supersede_proof(managed_buffer!(b"credential_v3"), managed_buffer!(b"credential_v1"), false);
+        })
+        .assert_user_error("New proof is already superseded");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let chain: Vec<ManagedBuffer<DebugApi>> = sc
+                .get_supersession_chain(&managed_buffer!(b"credential_v1"))
+                .to_vec()
+                .into_iter()
+                .collect();
+            assert_eq!(chain.len(), 3);
+            assert_eq!(chain[2], managed_buffer!(b"credential_v3"));
+
+            let v1 = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"credential_v1")).into_option().unwrap();
+            assert!(v1.revoked);
+            assert_eq!(v1.superseded_by, managed_buffer!(b"credential_v2"));
+
+            let v2 = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"credential_v2")).into_option().unwrap();
+            assert!(!v2.revoked);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_fee_paid_receipt() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(1000));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(25), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PAID_CERTIFICATE"),
+                managed_buffer!(b"paid_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"FREE_CERTIFICATE"),
+                managed_buffer!(b"free_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(15), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PAID_CERTIFICATE_2"),
+                managed_buffer!(b"paid_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let paid = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"paid_001")).into_option().unwrap();
+            assert_eq!(paid.fee_paid, managed_biguint!(25));
+
+            let free = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"free_001")).into_option().unwrap();
+            assert_eq!(free.fee_paid, managed_biguint!(0));
+
+            assert_eq!(sc.get_total_fees_collected(), managed_biguint!(40));
+        })
+        .assert_ok();
+
+    blockchain.check_egld_balance(contract_wrapper.address_ref(), &rust_biguint!(40));
+}
+
+#[test]
+fn test_fee_required_on_every_certification_path() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(1000));
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_certification_fee(managed_biguint!(100));
+            sc.add_authorized_issuer(managed_address!(&issuer));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"AUTO_PROOF"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"ESCROW_PROOF"),
+                managed_buffer!(b"escrow_free_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"quorum_free_001"),
+                managed_buffer!(b"QUORUM_PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_auto(managed_buffer!(b"AUTO_PROOF"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_ok();
+
+    // Taxa platita la propunere este contabilizata la atingerea cvorumului
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"quorum_paid_001"),
+                managed_buffer!(b"QUORUM_PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"quorum_paid_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let quorum = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"quorum_paid_001")).into_option().unwrap();
+            assert_eq!(quorum.fee_paid, managed_biguint!(100));
+            assert_eq!(sc.get_total_fees_collected(), managed_biguint!(200));
+        })
+        .assert_ok();
+
+    blockchain.check_egld_balance(contract_wrapper.address_ref(), &rust_biguint!(200));
+}
+
+#[test]
+fn test_derived_category_fee_on_every_path() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(2000));
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_certification_fee(managed_biguint!(100));
+            sc.set_prefix_category(managed_buffer!(b"premium-"), managed_buffer!(b"premium"));
+            sc.set_category_fee(managed_buffer!(b"premium"), managed_biguint!(500));
+            sc.add_authorized_issuer(managed_address!(&issuer));
+        })
+        .assert_ok();
+
+    // Taxa de baza nu ajunge pentru un id care mapeaza pe categoria premium
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"ESCROW_PROOF"),
+                managed_buffer!(b"premium-escrow-001"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"premium-quorum-001"),
+                managed_buffer!(b"QUORUM_PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(500), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"premium-quorum-001"),
+                managed_buffer!(b"QUORUM_PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"premium-quorum-001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(500), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"ESCROW_PROOF"),
+                managed_buffer!(b"premium-escrow-001"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            for proof_id in [&b"premium-quorum-001"[..], &b"premium-escrow-001"[..]] {
+                let proof = sc.get_proof(&managed_address!(&user), &managed_buffer!(proof_id)).into_option().unwrap();
+                assert_eq!(proof.category, managed_buffer!(b"premium"));
+                assert_eq!(proof.fee_paid, managed_biguint!(500));
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_reindex_user_restores_counters() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let alice = blockchain.create_user_account(&rust_biguint!(0));
+    let bob = blockchain.create_user_account(&rust_biguint!(0));
+
+    for (user, prefix) in [(&alice, "alice"), (&bob, "bob")] {
+        for i in 1..=2 {
+            blockchain
+                .execute_tx(user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                    sc.certify_action(
+                        managed_buffer!(b"COUNTED_PROOF"),
+                        managed_buffer!(format!("{}_{}", prefix, i).as_bytes()),
+                        OptionalValue::None,
+                    );
+                })
+                .assert_ok();
+        }
+    }
+
+    // Desincronizare artificiala a contoarelor
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.user_proof_count(&managed_address!(&alice)).set(7);
+            sc.total_proofs().set(11);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.reindex_user(managed_address!(&alice));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(sc.recompute_total(0, 1));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(!sc.recompute_total(1, 1));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&alice)), 2);
+            assert_eq!(sc.get_total_proofs(), 4);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_migrate_legacy_proofs() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    // Storage scris de prima versiune a contractului: doar proof_text, timestamp, proof_id, metadata
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let user_address = managed_address!(&user);
+            for (proof_id, timestamp) in [(&b"legacy_001"[..], 1_000u64), (&b"legacy_002"[..], 2_000u64)] {
+                let legacy = LegacyProofData::<DebugApi> {
+                    proof_text: managed_buffer!(b"LEGACY_DIPLOMA"),
+                    timestamp,
+                    proof_id: managed_buffer!(proof_id),
+                    metadata: managed_buffer!(b"{\"v\": 1}"),
+                };
+                let mut encoded = ManagedBuffer::new();
+                legacy.top_encode(&mut encoded).unwrap();
+
+                sc.user_proofs_raw(&user_address, &managed_buffer!(proof_id)).set(encoded);
+                sc.user_proof_ids(&user_address).insert(managed_buffer!(proof_id));
+                sc.proof_owners(&managed_buffer!(proof_id)).set(&user_address);
+            }
+            sc.user_proof_count(&user_address).set(2);
+            sc.total_proofs().set(2);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(sc.migrate_proofs(managed_address!(&user), 0, 1));
+            assert!(!sc.migrate_proofs(managed_address!(&user), 1, 1));
+        })
+        .assert_ok();
+
+    // Re-rularea sare inregistrarile deja migrate
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(!sc.migrate_proofs(managed_address!(&user), 0, 10));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            let proof = sc
+                .get_proof(&user_address, &managed_buffer!(b"legacy_002"))
+                .into_option()
+                .unwrap();
+            assert_eq!(proof.proof_text, managed_buffer!(b"LEGACY_DIPLOMA"));
+            assert_eq!(proof.timestamp, 2_000);
+            assert_eq!(proof.metadata, managed_buffer!(b"{\"v\": 1}"));
+            assert_eq!(proof.issuer, user_address);
+            assert!(!proof.revoked);
+
+            assert!(sc.verify_proof_integrity(&managed_buffer!(b"legacy_001")));
+            assert_eq!(sc.get_total_proofs(), 2);
+            assert_eq!(sc.get_user_proof_count(&user_address), 2);
+            assert_eq!(sc.issuer_proof_count(&user_address).get(), 2);
+            assert_eq!(sc.all_proof_ids().len(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_bookmark_others_proofs() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let alice = blockchain.create_user_account(&rust_biguint!(0));
+    let bob = blockchain.create_user_account(&rust_biguint!(0));
+
+    for i in 1..=3 {
+        blockchain
+            .execute_tx(&alice, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(format!("ALICE_BADGE_{}", i).as_bytes()),
+                    managed_buffer!(format!("alice_badge_{}", i).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    for i in 1..=3 {
+        blockchain
+            .execute_tx(&bob, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.bookmark_proof(managed_buffer!(format!("alice_badge_{}", i).as_bytes()));
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&bob, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.bookmark_proof(managed_buffer!(b"missing"));
+        })
+        .assert_user_error("Proof does not exist");
+
+    blockchain
+        .execute_tx(&bob, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.unbookmark_proof(managed_buffer!(b"alice_badge_2"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let all = sc.get_bookmarks(&managed_address!(&bob), 0, 10);
+            assert_eq!(all.len(), 2);
+
+            let first_page = sc.get_bookmarks(&managed_address!(&bob), 0, 1);
+            assert_eq!(first_page.len(), 1);
+
+            assert_eq!(sc.get_bookmarks(&managed_address!(&alice), 0, 10).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_find_proof_by_content_hash() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let alice = blockchain.create_user_account(&rust_biguint!(0));
+    let bob = blockchain.create_user_account(&rust_biguint!(0));
+    let document_hash = b"9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_enforce_unique_content_hash(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&alice, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"CONTRACT_SIGNED"),
+                managed_buffer!(b"doc_001"),
+                CertifyOptions {
+                    content_hash: managed_buffer!(document_hash),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&bob, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"CONTRACT_SIGNED_AGAIN"),
+                managed_buffer!(b"doc_002"),
+                CertifyOptions {
+                    content_hash: managed_buffer!(document_hash),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Content hash already certified");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof_id = sc.find_proof_by_hash(&managed_buffer!(document_hash)).into_option().unwrap();
+            assert_eq!(proof_id, managed_buffer!(b"doc_001"));
+
+            assert!(sc.find_proof_by_hash(&managed_buffer!(b"unknown_hash")).into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_category_allowlist_enforcement() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_allowed_category(managed_buffer!(b"Certificate"));
+            sc.add_allowed_category(managed_buffer!(b"Badge"));
+            sc.set_enforce_category_allowlist(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"COURSE_COMPLETED"),
+                managed_buffer!(b"course_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"Certificate"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"COURSE_COMPLETED"),
+                managed_buffer!(b"course_002"),
+                CertifyOptions {
+                    category: managed_buffer!(b"Certicate"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Unknown category");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_allowed_categories().len(), 2);
+
+            let proof_data = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"course_001")).into_option().unwrap();
+            assert_eq!(proof_data.category, managed_buffer!(b"Certificate"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_user_proofs_by_category() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    let proofs: [(&[u8], &[u8]); 3] = [
+        (b"cert_001", b"Certificate"),
+        (b"badge_001", b"Badge"),
+        (b"cert_002", b"Certificate"),
+    ];
+
+    for (proof_id, category) in proofs.iter() {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_with_options(
+                    managed_buffer!(b"CATEGORIZED_PROOF"),
+                    managed_buffer!(proof_id),
+                    CertifyOptions {
+                        category: managed_buffer!(category),
+                        ..Default::default()
+                    },
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+
+            let certificates = sc.get_user_proofs_by_category(&user_address, &managed_buffer!(b"Certificate"), 0, 10);
+            assert_eq!(certificates.len(), 2);
+            for proof_data in certificates.to_vec().iter() {
+                assert_eq!(proof_data.category, managed_buffer!(b"Certificate"));
+            }
+
+            let badges = sc.get_user_proofs_by_category(&user_address, &managed_buffer!(b"Badge"), 0, 10);
+            assert_eq!(badges.len(), 1);
+
+            let second_page = sc.get_user_proofs_by_category(&user_address, &managed_buffer!(b"Certificate"), 1, 10);
+            assert_eq!(second_page.len(), 1);
+
+            let unknown = sc.get_user_proofs_by_category(&user_address, &managed_buffer!(b"Diploma"), 0, 10);
+            assert_eq!(unknown.len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_rate_limit_window() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_rate_limit(2, 3_600);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(10_000);
+
+    for i in 1..=2 {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"BURST_PROOF"),
+                    managed_buffer!(format!("burst_{}", i).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"BURST_PROOF"),
+                managed_buffer!(b"burst_3"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Rate limit exceeded");
+
+    // Dupa expirarea ferestrei, certificarea este din nou permisa
+    blockchain.set_block_timestamp(10_000 + 3_600);
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"BURST_PROOF"),
+                managed_buffer!(b"burst_3"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_schema_version_after_init_and_upgrade() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_schema_version(), PROOF_SCHEMA_VERSION);
+        })
+        .assert_ok();
+
+    // Simulam o versiune veche stocata inainte de upgrade
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.schema_version().set(0);
+            sc.upgrade(PROOF_SCHEMA_VERSION);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_schema_version(), PROOF_SCHEMA_VERSION);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proof_collections() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let other = blockchain.create_user_account(&rust_biguint!(0));
+
+    for module in 1..=2 {
+        blockchain
+            .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(format!("RUST_COURSE_MODULE_{}", module).as_bytes()),
+                    managed_buffer!(format!("rust_module_{}", module).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.create_collection(
+                managed_buffer!(b"rust_course"),
+                managed_buffer!(b"Rust Course 2025"),
+                OptionalValue::None,
+            );
+            sc.create_collection(
+                managed_buffer!(b"other_course"),
+                managed_buffer!(b"Other Course"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    for module in 1..=2 {
+        blockchain
+            .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.add_to_collection(
+                    managed_buffer!(b"rust_course"),
+                    managed_buffer!(format!("rust_module_{}", module).as_bytes()),
+                );
+            })
+            .assert_ok();
+    }
+
+    // O dovada poate apartine unei singure colectii
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_to_collection(managed_buffer!(b"other_course"), managed_buffer!(b"rust_module_1"));
+        })
+        .assert_user_error("Proof already belongs to a collection");
+
+    blockchain
+        .execute_tx(&other, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_to_collection(managed_buffer!(b"rust_course"), managed_buffer!(b"rust_module_1"));
+        })
+        .assert_user_error("Only collection owner can add proofs");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_collection_proofs(&managed_buffer!(b"rust_course"), 0, 10).len(), 2);
+
+            let collection_id = sc.get_proof_collection(&managed_buffer!(b"rust_module_2")).into_option().unwrap();
+            assert_eq!(collection_id, managed_buffer!(b"rust_course"));
+
+            let collection = sc.get_collection(&managed_buffer!(b"rust_course")).into_option().unwrap();
+            assert_eq!(collection.owner, managed_address!(&issuer));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_sweep_expired_with_bounty() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(1000));
+    let sweeper = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_sweep_bounty(managed_biguint!(10));
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_000);
+
+    // Doua dovezi temporare (cea platita alimenteaza rezerva) si una permanenta
+    let proofs: [(&[u8], u64, u64); 3] = [
+        (b"temp_001", 100, 100),
+        (b"temp_002", 100, 0),
+        (b"permanent_001", 0, 0),
+    ];
+    for (proof_id, validity, fee) in proofs.iter() {
+        blockchain
+            .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(*fee), |sc| {
+                sc.certify_with_options(
+                    managed_buffer!(b"EVENT_PASS"),
+                    managed_buffer!(proof_id),
+                    CertifyOptions {
+                        validity_seconds: Some(*validity),
+                        ..Default::default()
+                    },
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain.set_block_timestamp(1_200);
+
+    blockchain
+        .execute_tx(&sweeper, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"temp_001"));
+            proof_ids.push(managed_buffer!(b"permanent_001"));
+            proof_ids.push(managed_buffer!(b"temp_002"));
+            proof_ids.push(managed_buffer!(b"missing"));
+
+            assert_eq!(sc.sweep_expired(proof_ids), 2);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.proof_exists(&managed_buffer!(b"temp_001")));
+            assert!(!sc.proof_exists(&managed_buffer!(b"temp_002")));
+            assert!(sc.proof_exists(&managed_buffer!(b"permanent_001")));
+
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&issuer)), 1);
+            assert_eq!(sc.get_total_proofs(), 1);
+            assert_eq!(sc.get_fee_reserve(), managed_biguint!(80));
+        })
+        .assert_ok();
+
+    blockchain.check_egld_balance(&sweeper, &rust_biguint!(20));
+}
+
+#[test]
+fn test_delegated_editor_permissions() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let proof_owner = blockchain.create_user_account(&rust_biguint!(0));
+    let assistant = blockchain.create_user_account(&rust_biguint!(0));
+    let proof_id = managed_buffer!(b"delegated_001");
+
+    blockchain
+        .execute_tx(&proof_owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"DRAFT_TEXT"),
+                managed_buffer!(b"delegated_001"),
+                OptionalValue::None,
+            );
+            sc.grant_editor(managed_buffer!(b"delegated_001"), managed_address!(&assistant));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&assistant, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(
+                managed_buffer!(b"delegated_001"),
+                managed_buffer!(b"EDITED_BY_ASSISTANT"),
+                OptionalValue::Some(managed_buffer!(b"{\"edited\": true}")),
+            );
+        })
+        .assert_ok();
+
+    // Editorii nu pot transfera dovada
+    blockchain
+        .execute_tx(&assistant, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"delegated_001"), managed_address!(&assistant));
+        })
+        .assert_user_error("Only proof owner can transfer");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_editors(&proof_id).len(), 1);
+
+            let proof_data = sc.get_proof(&managed_address!(&proof_owner), &proof_id).into_option().unwrap();
+            assert_eq!(proof_data.proof_text, managed_buffer!(b"EDITED_BY_ASSISTANT"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&proof_owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_editor(managed_buffer!(b"delegated_001"), managed_address!(&assistant));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&assistant, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(
+                managed_buffer!(b"delegated_001"),
+                managed_buffer!(b"SHOULD_FAIL"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Only proof owner or editor can update");
+}
+
+#[test]
+fn test_proof_encoded_roundtrip() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"ANCHORED_PROOF"),
+                managed_buffer!(b"anchored_001"),
+                OptionalValue::Some(managed_buffer!(b"{\"anchor\": \"0xabc\"}")),
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            let proof_id = managed_buffer!(b"anchored_001");
+
+            let encoded = sc.get_proof_encoded(&user_address, &proof_id).into_option().unwrap();
+            let decoded = ProofView::<DebugApi>::top_decode(encoded).unwrap();
+            let original = sc.get_proof(&user_address, &proof_id).into_option().unwrap();
+            assert_eq!(decoded, original);
+
+            assert!(sc.get_proof_encoded(&user_address, &managed_buffer!(b"missing")).into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_attestation_threshold_for_validity() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let attester_1 = blockchain.create_user_account(&rust_biguint!(0));
+    let attester_2 = blockchain.create_user_account(&rust_biguint!(0));
+    let proof_id = managed_buffer!(b"audited_001");
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"SECURITY_AUDIT_PASSED"),
+                managed_buffer!(b"audited_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Pragul 0 pastreaza comportamentul existent
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.is_proof_active(&proof_id));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_attestation_threshold(2);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester_1, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"audited_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"audited_001"), OptionalValue::None);
+        })
+        .assert_user_error("Owner cannot attest own proof");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_attestation_count(&proof_id), 1);
+            assert!(!sc.is_proof_active(&proof_id));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester_2, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"audited_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_attestation_count(&proof_id), 2);
+            assert!(sc.is_proof_active(&proof_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_transfer_handshake() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let sender = blockchain.create_user_account(&rust_biguint!(0));
+    let recipient = blockchain.create_user_account(&rust_biguint!(0));
+
+    for proof_id in [b"handshake_001", b"handshake_002"] {
+        blockchain
+            .execute_tx(&sender, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"TRANSFERABLE_BADGE"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+                sc.offer_transfer(managed_buffer!(proof_id), managed_address!(&recipient));
+            })
+            .assert_ok();
+    }
+
+    // Ownership-ul nu se schimba inainte de acceptare
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof_id = managed_buffer!(b"handshake_001");
+            assert_eq!(sc.get_proof_owner(&proof_id).into_option().unwrap(), managed_address!(&sender));
+            assert_eq!(sc.get_pending_transfer(&proof_id).into_option().unwrap(), managed_address!(&recipient));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&recipient, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.accept_transfer(managed_buffer!(b"handshake_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&sender, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.cancel_transfer(managed_buffer!(b"handshake_002"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&recipient, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.accept_transfer(managed_buffer!(b"handshake_002"));
+        })
+        .assert_user_error("No pending transfer for caller");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let accepted = managed_buffer!(b"handshake_001");
+            assert_eq!(sc.get_proof_owner(&accepted).into_option().unwrap(), managed_address!(&recipient));
+            assert!(sc.get_pending_transfer(&accepted).into_option().is_none());
+
+            let cancelled = managed_buffer!(b"handshake_002");
+            assert_eq!(sc.get_proof_owner(&cancelled).into_option().unwrap(), managed_address!(&sender));
+
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&sender)), 1);
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&recipient)), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proofs_above_score_with_different_decimals() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let student = blockchain.create_user_account(&rust_biguint!(0));
+
+    // 3.75 cu doua zecimale si 3.5 cu trei zecimale
+    let grades: [(&[u8], u64, u8); 2] = [(b"gpa_2024", 375, 2), (b"gpa_2025", 3_500, 3)];
+    for (proof_id, value, decimals) in grades.iter() {
+        blockchain
+            .execute_tx(&student, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_with_options(
+                    managed_buffer!(b"GPA_TRANSCRIPT"),
+                    managed_buffer!(proof_id),
+                    CertifyOptions {
+                        score_value: *value,
+                        score_decimals: *decimals,
+                        ..Default::default()
+                    },
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let student_address = managed_address!(&student);
+
+            // Peste 3.5 (35, 1): doar 3.75
+            let above: Vec<ManagedBuffer<DebugApi>> = sc
+                .get_proofs_above_score(&student_address, 35, 1)
+                .to_vec()
+                .into_iter()
+                .collect();
+            assert_eq!(above, vec![managed_buffer!(b"gpa_2024")]);
+
+            // Peste 3.4 (34, 1): ambele
+            assert_eq!(sc.get_proofs_above_score(&student_address, 34, 1).len(), 2);
+
+            // Peste 3.75 (3750, 3): niciuna
+            assert_eq!(sc.get_proofs_above_score(&student_address, 3_750, 3).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_freeze_all_user_proofs() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    for i in 1..=3 {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"HELD_PROOF"),
+                    managed_buffer!(format!("held_{}", i).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    // Doua pagini: prima lasa dovezi neprocesate
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(sc.freeze_all_proofs(managed_address!(&user), 0, 2));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(!sc.freeze_all_proofs(managed_address!(&user), 2, 2));
+        })
+        .assert_ok();
+
+    for i in 1..=3 {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.update_proof(
+                    managed_buffer!(format!("held_{}", i).as_bytes()),
+                    managed_buffer!(b"TAMPERED"),
+                    OptionalValue::None,
+                );
+            })
+            .assert_user_error("Proof is frozen");
+    }
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert!(!sc.unfreeze_all_proofs(managed_address!(&user), 0, 10));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(managed_buffer!(b"held_1"), managed_buffer!(b"RELEASED"), OptionalValue::None);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_compressed_proof_text_cap() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(&[b'z'; 2_000]),
+                managed_buffer!(b"compressed_001"),
+                CertifyOptions {
+                    compression_algo: managed_buffer!(b"zstd"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    // Acelasi text necomprimat depaseste limita normala
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(&[b'z'; 2_000]),
+                managed_buffer!(b"plain_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof text must be between 1 and 500 characters");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(&[b'z'; 4_097]),
+                managed_buffer!(b"compressed_002"),
+                CertifyOptions {
+                    compression_algo: managed_buffer!(b"zstd"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Compressed proof text must be between 1 and 4096 bytes");
+
+    // Minimul global ramane valabil pentru textele comprimate
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_proof_text_len(5);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"zzz"),
+                managed_buffer!(b"compressed_short"),
+                CertifyOptions {
+                    compression_algo: managed_buffer!(b"zstd"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Compressed proof text must be between 5 and 4096 bytes");
+
+    // Limitele per emitent se aplica si textelor comprimate
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_issuer_text_limit(managed_address!(&user), 10, 50);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(&[b'z'; 60]),
+                managed_buffer!(b"compressed_003"),
+                CertifyOptions {
+                    compression_algo: managed_buffer!(b"zstd"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Proof text length outside issuer limits");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let (compressed, algo) = sc
+                .get_compression_info(&managed_buffer!(b"compressed_001"))
+                .into_option()
+                .unwrap()
+                .into_tuple();
+            assert!(compressed);
+            assert_eq!(algo, managed_buffer!(b"zstd"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_attested_proofs_reverse_index() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let attester = blockchain.create_user_account(&rust_biguint!(0));
+
+    for proof_id in [b"skill_rust", b"skill_wasm"] {
+        blockchain
+            .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"SKILL_ENDORSEMENT"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+
+        blockchain
+            .execute_tx(&attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.attest_proof(managed_buffer!(proof_id), OptionalValue::None);
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let attested: Vec<ManagedBuffer<DebugApi>> = sc
+                .get_attested_proofs(&managed_address!(&attester), 0, 10)
+                .to_vec()
+                .into_iter()
+                .collect();
+            assert_eq!(attested.len(), 2);
+            assert!(attested.contains(&managed_buffer!(b"skill_rust")));
+            assert!(attested.contains(&managed_buffer!(b"skill_wasm")));
+
+            assert_eq!(sc.get_attested_proofs(&managed_address!(&attester), 1, 10).len(), 1);
+            assert_eq!(sc.get_attested_proofs(&managed_address!(&issuer), 0, 10).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_safe_proof_id_enforcement() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_enforce_safe_ids(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"URL_SAFE_PROOF"),
+                managed_buffer!(b"Clean-ID_2025"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    for bad_id in [&b"has space"[..], &b"path/like"[..]] {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"UNSAFE_PROOF"),
+                    managed_buffer!(bad_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_user_error("Proof ID contains invalid characters");
+    }
+
+    // Restrictia se aplica pe toate caile de creare, nu doar pe certify_action
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"UNSAFE_PROOF"),
+                managed_buffer!(b"has space"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof ID contains invalid characters");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"has space"),
+                managed_buffer!(b"UNSAFE_PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof ID contains invalid characters");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.is_proof_id_available(&managed_buffer!(b"has space"), &managed_address!(&user)));
+        })
+        .assert_ok();
+
+    // Fara restrictie orice octeti sunt permisi
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_enforce_safe_ids(false);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"ANY_BYTES_PROOF"),
+                managed_buffer!(b"has space"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_top_proofs_leaderboard() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let attesters = [
+        blockchain.create_user_account(&rust_biguint!(0)),
+        blockchain.create_user_account(&rust_biguint!(0)),
+        blockchain.create_user_account(&rust_biguint!(0)),
+    ];
+
+    for proof_id in [b"trend_a", b"trend_b", b"trend_c"] {
+        blockchain
+            .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"TRENDING_PROOF"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    // trend_a: 1 atestare, trend_b: 3 atestari, trend_c: 2 atestari
+    let attestations: [(&[u8], usize); 3] = [(b"trend_a", 1), (b"trend_b", 3), (b"trend_c", 2)];
+    for (proof_id, count) in attestations.iter() {
+        for attester in attesters.iter().take(*count) {
+            blockchain
+                .execute_tx(attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+                    sc.attest_proof(managed_buffer!(proof_id), OptionalValue::None);
+                })
+                .assert_ok();
+        }
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let top: Vec<(ManagedBuffer<DebugApi>, u32)> = sc
+                .get_top_proofs(10)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+            assert_eq!(
+                top,
+                vec![
+                    (managed_buffer!(b"trend_b"), 3),
+                    (managed_buffer!(b"trend_c"), 2),
+                    (managed_buffer!(b"trend_a"), 1),
+                ]
+            );
+
+            assert_eq!(sc.get_top_proofs(1).len(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_intra_block_proof_sequence() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    let certify = |blockchain: &mut BlockchainStateWrapper, proof_id: &[u8]| {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"ORDERED_PROOF"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    };
+
+    blockchain.set_block_nonce(100);
+    certify(&mut blockchain, b"same_block_1");
+    certify(&mut blockchain, b"same_block_2");
+
+    blockchain.set_block_nonce(101);
+    certify(&mut blockchain, b"next_block_1");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let first = sc.get_proof_sequence(&managed_buffer!(b"same_block_1")).into_option().unwrap();
+            let second = sc.get_proof_sequence(&managed_buffer!(b"same_block_2")).into_option().unwrap();
+            assert_eq!(first, 0);
+            assert_eq!(second, 1);
+
+            // Secventa se reseteaza la schimbarea nonce-ului
+            let next = sc.get_proof_sequence(&managed_buffer!(b"next_block_1")).into_option().unwrap();
+            assert_eq!(next, 0);
+
+            assert!(sc.get_proof_sequence(&managed_buffer!(b"missing")).into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_get_proofs_for_pairs() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let alice = blockchain.create_user_account(&rust_biguint!(0));
+    let bob = blockchain.create_user_account(&rust_biguint!(0));
+
+    for (user, proof_id) in [(&alice, b"pair_alice"), (&bob, b"pair_bob__")] {
+        blockchain
+            .execute_tx(user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"PAIRED_PROOF"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut pairs = MultiValueEncoded::new();
+            pairs.push((managed_address!(&alice), managed_buffer!(b"pair_alice")).into());
+            pairs.push((managed_address!(&bob), managed_buffer!(b"pair_bob__")).into());
+            // Owner gresit pentru un id existent
+            pairs.push((managed_address!(&alice), managed_buffer!(b"pair_bob__")).into());
+
+            let proofs = sc.get_proofs_for_pairs(pairs);
+            assert_eq!(proofs.len(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_service_status_codes() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_service_status(), 0);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_maintenance_mode(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_service_status(), 2);
+        })
+        .assert_ok();
+
+    // Mentenanta nu blocheaza certificarea
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"MAINTENANCE_PROOF"),
+                managed_buffer!(b"maintenance_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(true, OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_service_status(), 1);
+            assert!(sc.proof_exists(&managed_buffer!(b"maintenance_001")));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PAUSED_PROOF"),
+                managed_buffer!(b"paused_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(false, OptionalValue::None);
+            sc.set_maintenance_mode(false);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_service_status(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_suggest_proof_id_deterministic() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let first = sc.suggest_proof_id(&managed_buffer!(b"PROOF_TEXT"), &managed_buffer!(b"salt_a"));
+            let again = sc.suggest_proof_id(&managed_buffer!(b"PROOF_TEXT"), &managed_buffer!(b"salt_a"));
+            let other = sc.suggest_proof_id(&managed_buffer!(b"PROOF_TEXT"), &managed_buffer!(b"salt_b"));
+
+            // Aceleasi intrari produc aceeasi sugestie, salt diferit produce alta
+            assert_eq!(first, again);
+            assert!(first != other);
+            assert_eq!(first.len(), 32);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_parent_revocation_flags_direct_children() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_propagate_parent_revocation(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF_PARENT_001"),
+                managed_buffer!(b"parent_001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF_CHILD_001"),
+                managed_buffer!(b"child_001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF_CHILD_002"),
+                managed_buffer!(b"child_002"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF_GRANDCHILD_001"),
+                managed_buffer!(b"grandchild_001"),
+                OptionalValue::None,
+            );
+
+            sc.link_parent_proof(managed_buffer!(b"child_001"), managed_buffer!(b"parent_001"));
+            sc.link_parent_proof(managed_buffer!(b"child_002"), managed_buffer!(b"parent_001"));
+            sc.link_parent_proof(managed_buffer!(b"grandchild_001"), managed_buffer!(b"child_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_proof(managed_buffer!(b"parent_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let statuses = sc.get_children_revocation_status(&managed_buffer!(b"parent_001"));
+            assert_eq!(statuses.len(), 2);
+            for status in statuses.into_iter() {
+                let (_child_id, flagged) = status.into_tuple();
+                assert!(flagged);
+            }
+
+            // Copiii nu sunt revocati, doar marcati ca suspecti
+            let child = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"child_001"))
+                .into_option()
+                .unwrap();
+            assert!(!child.revoked);
+
+            // Cascada se opreste la copiii directi
+            let grandchildren = sc.get_children_revocation_status(&managed_buffer!(b"child_001"));
+            for status in grandchildren.into_iter() {
+                let (_child_id, flagged) = status.into_tuple();
+                assert!(!flagged);
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_reputation_fee_discount() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let trusted = blockchain.create_user_account(&rust_biguint!(1000));
+    let newcomer = blockchain.create_user_account(&rust_biguint!(1000));
+    let attester = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_certification_fee(managed_biguint!(100));
+            // Discount-ul peste 100% este limitat la 100%
+            sc.set_reputation_fee_discount(1, 20_000);
+            sc.set_reputation_fee_discount(1, 5_000);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&trusted, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF_TRUSTED_001"),
+                managed_buffer!(b"trusted_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"trusted_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_issuer_reputation(&managed_address!(&trusted)), 1);
+            assert_eq!(sc.get_effective_certification_fee(&managed_address!(&trusted)), managed_biguint!(50));
+            assert_eq!(sc.get_effective_certification_fee(&managed_address!(&newcomer)), managed_biguint!(100));
+        })
+        .assert_ok();
+
+    // Emitentul cu reputatie plateste taxa redusa
+    blockchain
+        .execute_tx(&trusted, &contract_wrapper, &rust_biguint!(50), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF_TRUSTED_002"),
+                managed_buffer!(b"trusted_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Emitentul fara reputatie plateste taxa intreaga
+    blockchain
+        .execute_tx(&newcomer, &contract_wrapper, &rust_biguint!(50), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF_NEWCOMER_001"),
+                managed_buffer!(b"newcomer_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&newcomer, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF_NEWCOMER_001"),
+                managed_buffer!(b"newcomer_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_withdraw_fees_to_treasury() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(1000));
+    let treasury = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PAID_PROOF"),
+                managed_buffer!(b"paid_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Retragere partiala catre o adresa care nu este owner
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.withdraw_fees_to(
+                managed_address!(&treasury),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(40),
+            );
+        })
+        .assert_ok();
+
+    blockchain.check_egld_balance(&treasury, &rust_biguint!(40));
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_fee_reserve(), managed_biguint!(60));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.withdraw_fees_to(
+                managed_address!(&treasury),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(61),
+            );
+        })
+        .assert_user_error("Amount exceeds fee balance");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.withdraw_fees_to(
+                managed_address!(&user),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(10),
+            );
+        })
+        .assert_user_error("Endpoint can only be called by owner");
+}
+
+// Contract minim care inregistreaza notificarile primite de la contractul de dovezi
+mod proof_listener_mock {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait ProofListenerMock {
+        #[init]
+        fn init(&self) {}
+
+        #[endpoint(onProofCertified)]
+        fn on_proof_certified(&self, proof_id: ManagedBuffer, owner: ManagedAddress) {
+            self.last_proof_id().set(proof_id);
+            self.last_owner().set(owner);
+            self.notification_count().update(|count| *count += 1);
+        }
+
+        #[storage_mapper("lastProofId")]
+        fn last_proof_id(&self) -> SingleValueMapper<ManagedBuffer>;
+
+        #[storage_mapper("lastOwner")]
+        fn last_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+        #[storage_mapper("notificationCount")]
+        fn notification_count(&self) -> SingleValueMapper<u32>;
+    }
+}
+
+#[test]
+fn test_proof_listener_notified() {
+    use proof_listener_mock::ProofListenerMock;
+
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    let listener_wrapper = blockchain.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        proof_listener_mock::contract_obj,
+        "output/proof-listener-mock.wasm",
+    );
+    blockchain
+        .execute_tx(&owner, &listener_wrapper, &rust_biguint!(0), |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    // Fara listener certificarea functioneaza normal
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"UNNOTIFIED_PROOF"),
+                managed_buffer!(b"unnotified_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_proof_listener(managed_address!(listener_wrapper.address_ref()));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"NOTIFIED_PROOF"),
+                managed_buffer!(b"notified_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&listener_wrapper, |sc| {
+            assert_eq!(sc.notification_count().get(), 1);
+            assert_eq!(sc.last_proof_id().get(), managed_buffer!(b"notified_001"));
+            assert_eq!(sc.last_owner().get(), managed_address!(&user));
+        })
+        .assert_ok();
+
+    // Adresele care nu sunt contracte sunt respinse
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_proof_listener(managed_address!(&user));
+        })
+        .assert_user_error("Listener must be a smart contract");
+}
+
+#[test]
+fn test_user_proof_summaries() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_timestamp(1_700_000_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"A_VERY_LONG_PROOF_TEXT_THAT_LIST_VIEWS_DO_NOT_NEED"),
+                managed_buffer!(b"summary_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"diploma"),
+                    title: managed_buffer!(b"BSc Computer Science"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let summaries = sc.get_user_proof_summaries(&managed_address!(&user), 0, 10);
+            assert_eq!(summaries.len(), 1);
+
+            // Doar id, timestamp, categorie si titlu - fara proof_text
+            let (proof_id, timestamp, category, title) = summaries.into_iter().next().unwrap().into_tuple();
+            assert_eq!(proof_id, managed_buffer!(b"summary_001"));
+            assert_eq!(timestamp, 1_700_000_000);
+            assert_eq!(category, managed_buffer!(b"diploma"));
+            assert_eq!(title, managed_buffer!(b"BSc Computer Science"));
+
+            assert_eq!(sc.get_user_proof_summaries(&managed_address!(&user), 1, 10).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_ownership_challenge_response() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+
+    // Owner-ul are o adresa derivata dintr-o cheie ed25519 cunoscuta
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let holder = Address::from(signing_key.verifying_key().to_bytes());
+    blockchain.create_user_account_fixed_address(&holder, &rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&holder, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"OWNED_PROOF"),
+                managed_buffer!(b"owned_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    let mut challenge = Vec::new();
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let first = sc.build_ownership_challenge(&managed_buffer!(b"owned_001"), 42);
+            let again = sc.build_ownership_challenge(&managed_buffer!(b"owned_001"), 42);
+            let other_nonce = sc.build_ownership_challenge(&managed_buffer!(b"owned_001"), 43);
+            assert_eq!(first, again);
+            assert!(first != other_nonce);
+            challenge = first.to_boxed_bytes().into_vec();
+        })
+        .assert_ok();
+
+    let signature = signing_key.sign(&challenge).to_bytes();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.verify_ownership_response(
+                &managed_buffer!(b"owned_001"),
+                42,
+                &managed_buffer!(&signature[..])
+            ));
+
+            // Semnatura trunchiata sau dovada inexistenta
+            assert!(!sc.verify_ownership_response(
+                &managed_buffer!(b"owned_001"),
+                42,
+                &managed_buffer!(&signature[..32])
+            ));
+            assert!(!sc.verify_ownership_response(
+                &managed_buffer!(b"missing_001"),
+                42,
+                &managed_buffer!(&signature[..])
+            ));
+        })
+        .assert_ok();
+
+    // O semnatura de 64 de octeti dar gresita nu intoarce false: verify_ed25519 opreste executia
+    let mut wrong_signature = signature;
+    wrong_signature[0] ^= 0xff;
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            sc.verify_ownership_response(
+                &managed_buffer!(b"owned_001"),
+                42,
+                &managed_buffer!(&wrong_signature[..]),
+            );
+        })
+        .assert_error(10, "invalid signature");
+
+    // Semnatura corecta, dar pentru alt nonce
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            sc.verify_ownership_response(
+                &managed_buffer!(b"owned_001"),
+                43,
+                &managed_buffer!(&signature[..]),
+            );
+        })
+        .assert_error(10, "invalid signature");
+}
+
+#[test]
+fn test_category_derived_from_id_prefix() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_prefix_category(managed_buffer!(b"edu-"), managed_buffer!(b"education"));
+            sc.set_prefix_category(managed_buffer!(b"edu-phd-"), managed_buffer!(b"doctorate"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"edu-bsc-001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"edu-phd-001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"misc-001"),
+                OptionalValue::None,
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"edu-explicit-001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"custom"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let category_of = |proof_id: &[u8]| {
+                sc.get_proof(&managed_address!(&user), &managed_buffer!(proof_id))
+                    .into_option()
+                    .unwrap()
+                    .category
+            };
+
+            // Cel mai lung prefix potrivit castiga
+            assert_eq!(category_of(b"edu-bsc-001"), managed_buffer!(b"education"));
+            assert_eq!(category_of(b"edu-phd-001"), managed_buffer!(b"doctorate"));
+            // Fara potrivire categoria ramane goala, iar cea explicita are prioritate
+            assert_eq!(category_of(b"misc-001"), managed_buffer!(b""));
+            assert_eq!(category_of(b"edu-explicit-001"), managed_buffer!(b"custom"));
+
+            assert_eq!(sc.get_prefix_category_rules().len(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_flag_proof_and_uphold() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let reporter = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"SUSPICIOUS_PROOF"),
+                managed_buffer!(b"suspicious_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&reporter, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.flag_proof(managed_buffer!(b"suspicious_001"), managed_buffer!(b"forged issuer"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&reporter, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.flag_proof(managed_buffer!(b"suspicious_001"), managed_buffer!(b"again"));
+        })
+        .assert_user_error("Proof already flagged by caller");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_flag_count(&managed_buffer!(b"suspicious_001")), 1);
+        })
+        .assert_ok();
+
+    // Owner-ul contractului confirma semnalarea - dovada este revocata
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.resolve_flag(managed_buffer!(b"suspicious_001"), true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_flag_count(&managed_buffer!(b"suspicious_001")), 0);
+            let proof = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"suspicious_001"))
+                .into_option()
+                .unwrap();
+            assert!(proof.revoked);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.resolve_flag(managed_buffer!(b"suspicious_001"), false);
+        })
+        .assert_user_error("Proof has no flags");
+}
+
+#[test]
+fn test_category_breakdown() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"breakdown_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"diploma"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"breakdown_002"),
+                CertifyOptions {
+                    category: managed_buffer!(b"diploma"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"breakdown_003"),
+                CertifyOptions {
+                    category: managed_buffer!(b"badge"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut categories = MultiValueEncoded::new();
+            categories.push(managed_buffer!(b"diploma"));
+            categories.push(managed_buffer!(b"badge"));
+            categories.push(managed_buffer!(b"license"));
+
+            let breakdown: Vec<(ManagedBuffer<DebugApi>, u64)> = sc
+                .get_category_breakdown(categories)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+
+            // Categoriile fara dovezi apar cu 0
+            assert_eq!(
+                breakdown,
+                vec![
+                    (managed_buffer!(b"diploma"), 2),
+                    (managed_buffer!(b"badge"), 1),
+                    (managed_buffer!(b"license"), 0),
+                ]
+            );
+            assert_eq!(sc.get_total_proofs(), 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_immutable_proof_rejects_updates() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"ORIGINAL_TEXT"),
+                managed_buffer!(b"locked_001"),
+                CertifyOptions {
+                    immutable: true,
+                    ..Default::default()
+                },
+            );
+            sc.certify_action(
+                managed_buffer!(b"ORIGINAL_TEXT"),
+                managed_buffer!(b"mutable_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(
+                managed_buffer!(b"locked_001"),
+                managed_buffer!(b"NEW_TEXT"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof is immutable");
+
+    // Dovada mutabila poate fi actualizata in continuare
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(
+                managed_buffer!(b"mutable_001"),
+                managed_buffer!(b"NEW_TEXT"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let locked = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"locked_001"))
+                .into_option()
+                .unwrap();
+            assert!(locked.immutable);
+            assert_eq!(locked.proof_text, managed_buffer!(b"ORIGINAL_TEXT"));
+
+            let mutable = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"mutable_001"))
+                .into_option()
+                .unwrap();
+            assert!(!mutable.immutable);
+            assert_eq!(mutable.proof_text, managed_buffer!(b"NEW_TEXT"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_packed_flags_round_trip() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PACKED_PROOF"),
+                managed_buffer!(b"packed_001"),
+                CertifyOptions {
+                    encryption_scheme: managed_buffer!(b"aes-256-gcm"),
+                    immutable: true,
+                    ..Default::default()
+                },
+            );
+            sc.revoke_proof(managed_buffer!(b"packed_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.freeze_all_proofs(managed_address!(&user), 0, 10);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            // View-ul expune flag-urile decodificate ca booleeni
+            let view = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"packed_001"))
+                .into_option()
+                .unwrap();
+            assert!(view.encrypted && view.revoked && view.frozen && view.immutable);
+
+            let mut stored = sc.user_proofs(&managed_address!(&user), &managed_buffer!(b"packed_001")).get();
+            assert_eq!(stored.flags_version(), FLAGS_LAYOUT_VERSION as u8);
+
+            // Fiecare flag se poate modifica independent de celelalte
+            stored.set_revoked(false);
+            stored.set_frozen(false);
+            assert!(!stored.is_revoked() && !stored.is_frozen());
+            assert!(stored.is_encrypted() && stored.is_immutable());
+
+            stored.set_encrypted(false);
+            stored.set_frozen(true);
+            assert!(!stored.is_encrypted() && stored.is_frozen() && stored.is_immutable());
+            assert_eq!(stored.flags_version(), FLAGS_LAYOUT_VERSION as u8);
+
+            let view = stored.into_view();
+            assert!(!view.encrypted && !view.revoked && view.frozen && view.immutable);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_multi_token_fees_in_accounting_unit() {
+    const TOKEN_A: &[u8] = b"TOKA-123456";
+    const TOKEN_B: &[u8] = b"TOKB-654321";
+    const TOKEN_C: &[u8] = b"TOKC-abcdef";
+
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    blockchain.set_esdt_balance(&user, TOKEN_A, &rust_biguint!(100));
+    blockchain.set_esdt_balance(&user, TOKEN_B, &rust_biguint!(100));
+    blockchain.set_esdt_balance(&user, TOKEN_C, &rust_biguint!(100));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_fee_token_rate(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(TOKEN_A)), managed_biguint!(2));
+            sc.set_fee_token_rate(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(TOKEN_B)), managed_biguint!(5));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_esdt_transfer(&user, &contract_wrapper, TOKEN_A, 0, &rust_biguint!(10), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"token_fee_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_esdt_transfer(&user, &contract_wrapper, TOKEN_B, 0, &rust_biguint!(4), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"token_fee_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Tokenurile fara rata nu sunt acceptate
+    blockchain
+        .execute_esdt_transfer(&user, &contract_wrapper, TOKEN_C, 0, &rust_biguint!(4), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"token_fee_003"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Fee token not accepted");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            // 10 * 2 + 4 * 5
+            assert_eq!(sc.get_total_fees_in_unit(), managed_biguint!(40));
+
+            // Soldurile brute raman separate per token pentru retragere
+            let token_a = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(TOKEN_A));
+            let token_b = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(TOKEN_B));
+            assert_eq!(sc.get_token_fee_reserve(&token_a), managed_biguint!(10));
+            assert_eq!(sc.get_token_fee_reserve(&token_b), managed_biguint!(4));
+            assert_eq!(sc.get_total_fees_collected(), managed_biguint!(0));
+
+            let proof = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"token_fee_002"))
+                .into_option()
+                .unwrap();
+            assert_eq!(proof.fee_token, token_b);
+            assert_eq!(proof.fee_paid, managed_biguint!(4));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_sealed_contract_rejects_new_proofs() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"before_seal_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.seal_contract();
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"after_seal_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Contract is sealed");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"AUTO_PROOF"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_user_error("Contract is sealed");
+
+    // Sigilarea nu poate fi anulata
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.seal_contract();
+        })
+        .assert_user_error("Contract is already sealed");
+
+    // Revocarea functioneaza in continuare
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_proof(managed_buffer!(b"before_seal_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.is_sealed());
+            assert!(sc.proof_exists(&managed_buffer!(b"before_seal_001")));
+            assert!(!sc.proof_exists(&managed_buffer!(b"after_seal_001")));
+            assert_eq!(sc.get_total_proofs(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_user_proof_timeline_ascending() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    // Ordinea certificarii difera de ordinea timestamp-urilor
+    for (timestamp, proof_id) in [
+        (3_000u64, &b"timeline_c"[..]),
+        (1_000u64, &b"timeline_a"[..]),
+        (2_000u64, &b"timeline_b"[..]),
+    ] {
+        blockchain.set_block_timestamp(timestamp);
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"PROOF"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let timeline: Vec<(u64, ManagedBuffer<DebugApi>)> = sc
+                .get_user_proof_timeline(&managed_address!(&user), 0, 10)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+            assert_eq!(
+                timeline,
+                vec![
+                    (1_000, managed_buffer!(b"timeline_a")),
+                    (2_000, managed_buffer!(b"timeline_b")),
+                    (3_000, managed_buffer!(b"timeline_c")),
+                ]
+            );
+
+            // Paginarea se aplica dupa sortare
+            let page: Vec<(u64, ManagedBuffer<DebugApi>)> = sc
+                .get_user_proof_timeline(&managed_address!(&user), 1, 1)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+            assert_eq!(page, vec![(2_000, managed_buffer!(b"timeline_b"))]);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_issuer_text_limit_override() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let publisher = blockchain.create_user_account(&rust_biguint!(0));
+    let regular = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_issuer_text_limit(managed_address!(&publisher), 10, 1_000);
+        })
+        .assert_ok();
+
+    // 800 de caractere depasesc limita globala de 500, dar nu si override-ul
+    blockchain
+        .execute_tx(&publisher, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(&[b'x'; 800][..]),
+                managed_buffer!(b"long_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&regular, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(&[b'x'; 800][..]),
+                managed_buffer!(b"long_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof text must be between 1 and 500 characters");
+
+    // Minimul din override se aplica si el
+    blockchain
+        .execute_tx(&publisher, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"SHORT"),
+                managed_buffer!(b"short_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof text length outside issuer limits");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let (min, max) = sc.get_issuer_text_limit(&managed_address!(&publisher)).into_tuple();
+            assert_eq!((min, max), (10, 1_000));
+            let (min, max) = sc.get_issuer_text_limit(&managed_address!(&regular)).into_tuple();
+            assert_eq!((min, max), (1, 500));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_transfer_fee() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let seller = blockchain.create_user_account(&rust_biguint!(1000));
+    let buyer = blockchain.create_user_account(&rust_biguint!(1000));
+
+    blockchain
+        .execute_tx(&seller, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"transfer_fee_001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"transfer_fee_002"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"transfer_fee_003"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Cu taxa zero transferul ramane gratuit
+    blockchain
+        .execute_tx(&seller, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"transfer_fee_001"), managed_address!(&buyer));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_transfer_fee(managed_biguint!(25));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&seller, &contract_wrapper, &rust_biguint!(10), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"transfer_fee_002"), managed_address!(&buyer));
+        })
+        .assert_user_error("Incorrect transfer fee");
+
+    blockchain
+        .execute_tx(&seller, &contract_wrapper, &rust_biguint!(25), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"transfer_fee_002"), managed_address!(&buyer));
+        })
+        .assert_ok();
+
+    // Handshake-ul offer/accept plateste aceeasi taxa, la acceptare
+    blockchain
+        .execute_tx(&seller, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.offer_transfer(managed_buffer!(b"transfer_fee_003"), managed_address!(&buyer));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&buyer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.accept_transfer(managed_buffer!(b"transfer_fee_003"));
+        })
+        .assert_user_error("Incorrect transfer fee");
+
+    blockchain
+        .execute_tx(&buyer, &contract_wrapper, &rust_biguint!(25), |sc| {
+            sc.accept_transfer(managed_buffer!(b"transfer_fee_003"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"transfer_fee_002")).into_option().unwrap(),
+                managed_address!(&buyer)
+            );
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"transfer_fee_003")).into_option().unwrap(),
+                managed_address!(&buyer)
+            );
+            assert_eq!(sc.get_fee_reserve(), managed_biguint!(50));
+            assert_eq!(sc.get_total_fees_collected(), managed_biguint!(50));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_archive_old_proofs() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_retention_period(1_000);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(10_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"old_001"),
+                CertifyOptions {
+                    content_hash: managed_buffer!(b"hash_old_001"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(10_500);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"recent_001"),
+                CertifyOptions {
+                    content_hash: managed_buffer!(b"hash_recent_001"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(11_200);
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"recent_001"));
+            sc.archive_old_proofs(proof_ids);
+        })
+        .assert_user_error("Proof is too recent to archive");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"old_001"));
+            assert_eq!(sc.archive_old_proofs(proof_ids), 1);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            // Inregistrarea completa este eliberata, forma compacta ramane
+            assert!(!sc.proof_exists(&managed_buffer!(b"old_001")));
+            let archived = sc.get_archived_proof(&managed_buffer!(b"old_001")).into_option().unwrap();
+            assert_eq!(archived.owner, managed_address!(&user));
+            assert_eq!(archived.timestamp, 10_000);
+            assert_eq!(archived.content_hash, managed_buffer!(b"hash_old_001"));
+
+            assert!(sc.proof_exists(&managed_buffer!(b"recent_001")));
+            assert!(sc.get_archived_proof(&managed_buffer!(b"recent_001")).into_option().is_none());
+            assert_eq!(sc.get_total_proofs(), 1);
+        })
+        .assert_ok();
+
+    // Id-ul arhivat nu poate fi refolosit
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"old_001"),
+                CertifyOptions {
+                    content_hash: managed_buffer!(b"hash_old_001"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Proof ID already exists");
+}
+
+#[test]
+fn test_balance_breakdown() {
+    const STRAY_TOKEN: &[u8] = b"STRAY-123456";
+
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(1000));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PAID_PROOF"),
+                managed_buffer!(b"breakdown_paid_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Fonduri trimise direct contractului, in afara taxelor
+    blockchain.set_egld_balance(contract_wrapper.address_ref(), &rust_biguint!(130));
+    blockchain.set_esdt_balance(contract_wrapper.address_ref(), STRAY_TOKEN, &rust_biguint!(40));
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let (reserve, rescuable) = sc.get_balance_breakdown(EgldOrEsdtTokenIdentifier::egld()).into_tuple();
+            assert_eq!(reserve, managed_biguint!(100));
+            assert_eq!(rescuable, managed_biguint!(30));
+
+            let stray = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(STRAY_TOKEN));
+            let (reserve, rescuable) = sc.get_balance_breakdown(stray).into_tuple();
+            assert_eq!(reserve, managed_biguint!(0));
+            assert_eq!(rescuable, managed_biguint!(40));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_relay_certify() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let relayer = blockchain.create_user_account(&rust_biguint!(0));
+
+    let signing_key = SigningKey::from_bytes(&[11u8; 32]);
+    let user = Address::from(signing_key.verifying_key().to_bytes());
+    blockchain.create_user_account_fixed_address(&user, &rust_biguint!(0));
+
+    let mut message = Vec::new();
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            message = sc
+                .get_relay_message(
+                    &managed_address!(&user),
+                    &managed_buffer!(b"relayed_001"),
+                    &managed_buffer!(b"RELAYED_PROOF"),
+                    &managed_buffer!(b""),
+                )
+                .to_boxed_bytes()
+                .into_vec();
+        })
+        .assert_ok();
+    let signature = signing_key.sign(&message).to_bytes();
+
+    // Relayer-ul plateste gas-ul, dovada apartine utilizatorului
+    blockchain
+        .execute_tx(&relayer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.relay_certify(
+                managed_address!(&user),
+                managed_buffer!(b"RELAYED_PROOF"),
+                managed_buffer!(b"relayed_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&signature[..]),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"relayed_001"))
+                .into_option()
+                .unwrap();
+            assert_eq!(proof.issuer, managed_address!(&user));
+            assert_eq!(sc.get_relay_nonce(&managed_address!(&user)), 1);
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&relayer)), 0);
+        })
+        .assert_ok();
+
+    // Replay-ul aceleiasi cereri esueaza: mesajul semnat includea nonce-ul deja consumat
+    blockchain
+        .execute_tx(&relayer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.relay_certify(
+                managed_address!(&user),
+                managed_buffer!(b"RELAYED_PROOF"),
+                managed_buffer!(b"relayed_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&signature[..]),
+                OptionalValue::None,
+            );
+        })
+        .assert_error(10, "invalid signature");
+}
+
+#[test]
+fn test_split_min_max_text_length() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    // Doar minimul - maximul implicit de 500 ramane
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_proof_text_len(5);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"ABC"),
+                managed_buffer!(b"short_text_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof text must be between 5 and 500 characters");
+
+    // Doar maximul
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_max_proof_text_len(600);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(&[b'x'; 550][..]),
+                managed_buffer!(b"long_text_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Combinatii invalide, indiferent de ordinea setarii
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_proof_text_len(700);
+        })
+        .assert_user_error("Minimum length cannot exceed maximum");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_max_proof_text_len(4);
+        })
+        .assert_user_error("Maximum length cannot be below minimum");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let (min, max) = sc.get_issuer_text_limit(&managed_address!(&user)).into_tuple();
+            assert_eq!((min, max), (5, 600));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_purge_revoked_proofs() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let other = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"purge_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"badge"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"purge_002"),
+                CertifyOptions {
+                    category: managed_buffer!(b"badge"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"keep_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"badge"),
+                    ..Default::default()
+                },
+            );
+            sc.revoke_proof(managed_buffer!(b"purge_001"));
+            sc.revoke_proof(managed_buffer!(b"purge_002"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&other, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"other_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"badge"),
+                    ..Default::default()
+                },
+            );
+            sc.revoke_proof(managed_buffer!(b"other_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(true, OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"purge_001"));
+            sc.purge_revoked(proof_ids);
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(false, OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"purge_001"));
+            proof_ids.push(managed_buffer!(b"purge_002"));
+            // Dovada activa si dovada altui utilizator sunt ignorate
+            proof_ids.push(managed_buffer!(b"keep_001"));
+            proof_ids.push(managed_buffer!(b"other_001"));
+            assert_eq!(sc.purge_revoked(proof_ids), 2);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.proof_exists(&managed_buffer!(b"purge_001")));
+            assert!(!sc.proof_exists(&managed_buffer!(b"purge_002")));
+            assert!(sc.proof_exists(&managed_buffer!(b"keep_001")));
+            assert!(sc.proof_exists(&managed_buffer!(b"other_001")));
+
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&user)), 1);
+            assert_eq!(sc.get_total_proofs(), 2);
+
+            let mut categories = MultiValueEncoded::new();
+            categories.push(managed_buffer!(b"badge"));
+            let (_, count) = sc.get_category_breakdown(categories).into_iter().next().unwrap().into_tuple();
+            assert_eq!(count, 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_update_emits_text_diff() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_emit_update_diffs(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"OLD_TEXT"),
+                managed_buffer!(b"diff_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    let tx_result = blockchain.execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+        sc.update_proof(managed_buffer!(b"diff_001"), managed_buffer!(b"NEW_TEXT"), OptionalValue::None);
+    });
+    tx_result.assert_ok();
+
+    let log = tx_result
+        .result_logs
+        .iter()
+        .find(|log| log.topics[0] == b"proofUpdatedDetailed".to_vec())
+        .expect("missing proofUpdatedDetailed event");
+
+    // ProofTextDiff codificat: lungime pe 4 octeti + octeti, pentru textul vechi si cel nou
+    let mut expected = Vec::new();
+    for text in [&b"OLD_TEXT"[..], &b"NEW_TEXT"[..]] {
+        expected.extend_from_slice(&(text.len() as u32).to_be_bytes());
+        expected.extend_from_slice(text);
+    }
+    assert_eq!(log.data[0], expected);
+}
+
+#[test]
+fn test_weighted_attestation_score() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let auditor = blockchain.create_user_account(&rust_biguint!(0));
+    let peer = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_attester_weight(managed_address!(&auditor), 5);
+            sc.set_weighted_attestation_threshold(6);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"WEIGHTED_PROOF"),
+                managed_buffer!(b"weighted_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&auditor, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"weighted_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_weighted_attestation_score(&managed_buffer!(b"weighted_001")), 5);
+            assert!(!sc.is_proof_active(&managed_buffer!(b"weighted_001")));
+        })
+        .assert_ok();
+
+    // Atestatorul fara pondere configurata conteaza 1
+    blockchain
+        .execute_tx(&peer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"weighted_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_weighted_attestation_score(&managed_buffer!(b"weighted_001")), 6);
+            assert_eq!(sc.get_attestation_count(&managed_buffer!(b"weighted_001")), 2);
+            assert!(sc.is_proof_active(&managed_buffer!(b"weighted_001")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_required_metadata_prefix() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_required_metadata_prefix(managed_buffer!(b"schema:v1;"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"schema_ok_001"),
+                OptionalValue::Some(managed_buffer!(b"schema:v1;grade=A")),
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"schema_bad_001"),
+                OptionalValue::Some(managed_buffer!(b"schema:v2;grade=A")),
+            );
+        })
+        .assert_user_error("Metadata schema mismatch");
+
+    // Metadata goala este respinsa cand prefixul este obligatoriu
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"schema_empty_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Metadata schema mismatch");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_required_metadata_prefix(), managed_buffer!(b"schema:v1;"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_user_proofs_by_epoch() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_epoch(100);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"epoch_100_a"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"epoch_100_b"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain.set_block_epoch(101);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"epoch_101_a"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut in_epoch_100: Vec<ManagedBuffer<DebugApi>> = sc
+                .get_user_proofs_by_epoch(&managed_address!(&user), 100)
+                .into_iter()
+                .collect();
+            in_epoch_100.sort_by_key(|id| id.to_boxed_bytes().into_vec());
+            assert_eq!(in_epoch_100, vec![managed_buffer!(b"epoch_100_a"), managed_buffer!(b"epoch_100_b")]);
+
+            let in_epoch_101: Vec<ManagedBuffer<DebugApi>> = sc
+                .get_user_proofs_by_epoch(&managed_address!(&user), 101)
+                .into_iter()
+                .collect();
+            assert_eq!(in_epoch_101, vec![managed_buffer!(b"epoch_101_a")]);
+
+            let proof = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"epoch_101_a"))
+                .into_option()
+                .unwrap();
+            assert_eq!(proof.epoch, 101);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_batch_update_metadata() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let other = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"batch_meta_001"),
+                OptionalValue::Some(managed_buffer!(b"old")),
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"batch_meta_002"),
+                OptionalValue::Some(managed_buffer!(b"old")),
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"batch_meta_003"),
+                OptionalValue::Some(managed_buffer!(b"old")),
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&other, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"foreign_meta_001"),
+                OptionalValue::Some(managed_buffer!(b"old")),
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut updates = MultiValueEncoded::new();
+            for proof_id in [&b"batch_meta_001"[..], b"batch_meta_002", b"batch_meta_003", b"foreign_meta_001"] {
+                updates.push((managed_buffer!(proof_id), managed_buffer!(b"accredited-2026")).into());
+            }
+            assert_eq!(sc.batch_update_metadata(updates), 3);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut updates = MultiValueEncoded::new();
+            updates.push((managed_buffer!(b"batch_meta_001"), managed_buffer!(&[b'm'; 2_000][..])).into());
+            sc.batch_update_metadata(updates);
+        })
+        .assert_user_error("Metadata too long");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            for proof_id in [&b"batch_meta_001"[..], b"batch_meta_002", b"batch_meta_003"] {
+                let proof = sc
+                    .get_proof(&managed_address!(&issuer), &managed_buffer!(proof_id))
+                    .into_option()
+                    .unwrap();
+                assert_eq!(proof.metadata, managed_buffer!(b"accredited-2026"));
+            }
+
+            // Dovada altui utilizator ramane neschimbata
+            let foreign = sc
+                .get_proof(&managed_address!(&other), &managed_buffer!(b"foreign_meta_001"))
+                .into_option()
+                .unwrap();
+            assert_eq!(foreign.metadata, managed_buffer!(b"old"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_upgrade_version_guard() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    // Aceeasi versiune ca cea stocata este respinsa
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.upgrade(PROOF_SCHEMA_VERSION);
+        })
+        .assert_user_error("Downgrade not allowed");
+
+    // Versiunea stocata este mai noua decat codul
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.schema_version().set(PROOF_SCHEMA_VERSION + 1);
+            sc.upgrade(PROOF_SCHEMA_VERSION);
+        })
+        .assert_user_error("Downgrade not allowed");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.schema_version().set(PROOF_SCHEMA_VERSION - 1);
+            sc.upgrade(PROOF_SCHEMA_VERSION - 1);
+        })
+        .assert_user_error("Schema version mismatch");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.upgrade(PROOF_SCHEMA_VERSION);
+        })
+        .assert_user_error("Only owner can upgrade");
+
+    // Upgrade de la o versiune mai veche la cea curenta
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.schema_version().set(PROOF_SCHEMA_VERSION - 1);
+            sc.upgrade(PROOF_SCHEMA_VERSION);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_schema_version(), PROOF_SCHEMA_VERSION);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_certify_cooldown() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_certify_cooldown(60);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"cooldown_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_059);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"cooldown_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Certify cooldown active");
+
+    // Dupa fereastra de 60 de secunde certificarea reuseste
+    blockchain.set_block_timestamp(1_060);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"cooldown_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Zero dezactiveaza pauza
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_certify_cooldown(0);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"cooldown_003"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_can_edit_proof() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let editor = blockchain.create_user_account(&rust_biguint!(0));
+    let stranger = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"editable_001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"revoked_edit_001"),
+                OptionalValue::None,
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"immutable_edit_001"),
+                CertifyOptions {
+                    immutable: true,
+                    ..Default::default()
+                },
+            );
+            sc.grant_editor(managed_buffer!(b"editable_001"), managed_address!(&editor));
+            sc.revoke_proof(managed_buffer!(b"revoked_edit_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let editable = managed_buffer!(b"editable_001");
+            // Owner si editor delegat pot edita, un strain nu
+            assert!(sc.can_edit_proof(&editable, &managed_address!(&user)));
+            assert!(sc.can_edit_proof(&editable, &managed_address!(&editor)));
+            assert!(!sc.can_edit_proof(&editable, &managed_address!(&stranger)));
+
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"revoked_edit_001"), &managed_address!(&user)));
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"immutable_edit_001"), &managed_address!(&user)));
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"missing_001"), &managed_address!(&user)));
+        })
+        .assert_ok();
+
+    // Endpoint-urile de editare aplica aceeasi regula ca view-ul
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(managed_buffer!(b"revoked_edit_001"), managed_buffer!(b"NEW"), OptionalValue::None);
+        })
+        .assert_user_error("Proof is revoked");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_title(managed_buffer!(b"revoked_edit_001"), managed_buffer!(b"New title"));
+        })
+        .assert_user_error("Proof is revoked");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.freeze_all_proofs(managed_address!(&user), 0, 10);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"editable_001"), &managed_address!(&user)));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.unfreeze_all_proofs(managed_address!(&user), 0, 10);
+            sc.set_paused(true, OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"editable_001"), &managed_address!(&editor)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_certify_with_referrer() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let referrer = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"referred_001"),
+                CertifyOptions {
+                    referrer: Some(managed_address!(&referrer)),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"referred_002"),
+                CertifyOptions {
+                    referrer: Some(managed_address!(&referrer)),
+                    ..Default::default()
+                },
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"unreferred_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"self_referred_001"),
+                CertifyOptions {
+                    referrer: Some(managed_address!(&user)),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Self-referral not allowed");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_referral_count(&managed_address!(&referrer)), 2);
+            assert_eq!(
+                sc.get_proof_referrer(&managed_buffer!(b"referred_001")).into_option().unwrap(),
+                managed_address!(&referrer)
+            );
+            assert!(sc.get_proof_referrer(&managed_buffer!(b"unreferred_001")).into_option().is_none());
+            assert!(!sc.proof_exists(&managed_buffer!(b"self_referred_001")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_user_category_count_follows_transfer() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let buyer = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"user_cat_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"diploma"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"user_cat_002"),
+                CertifyOptions {
+                    category: managed_buffer!(b"diploma"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"user_cat_003"),
+                CertifyOptions {
+                    category: managed_buffer!(b"badge"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"user_cat_001"), managed_address!(&buyer));
+        })
+        .assert_ok();
+
+    // Contorul categoriei trece de la vechiul owner la cel nou
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let diploma = managed_buffer!(b"diploma");
+            let badge = managed_buffer!(b"badge");
+            assert_eq!(sc.get_user_category_count(&managed_address!(&user), &diploma), 1);
+            assert_eq!(sc.get_user_category_count(&managed_address!(&user), &badge), 1);
+            assert_eq!(sc.get_user_category_count(&managed_address!(&buyer), &diploma), 1);
+            assert_eq!(sc.get_user_category_count(&managed_address!(&buyer), &badge), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_verify_batch() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let attester = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"batch_valid_001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"batch_revoked_001"),
+                OptionalValue::None,
+            );
+            sc.revoke_proof(managed_buffer!(b"batch_revoked_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"batch_valid_001"));
+            proof_ids.push(managed_buffer!(b"batch_revoked_001"));
+            proof_ids.push(managed_buffer!(b"batch_missing_001"));
+
+            let results: Vec<(ManagedBuffer<DebugApi>, bool)> = sc
+                .verify_batch(proof_ids)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+
+            assert_eq!(
+                results,
+                vec![
+                    (managed_buffer!(b"batch_valid_001"), true),
+                    (managed_buffer!(b"batch_revoked_001"), false),
+                    (managed_buffer!(b"batch_missing_001"), false),
+                ]
+            );
+        })
+        .assert_ok();
+
+    // Pragul de atestare se aplica si verificarii in lot
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_attestation_threshold(1);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"batch_valid_001"));
+            let (_, active) = sc.verify_batch(proof_ids).into_iter().next().unwrap().into_tuple();
+            assert!(!active);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"batch_valid_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"batch_valid_001"));
+            let (_, active) = sc.verify_batch(proof_ids).into_iter().next().unwrap().into_tuple();
+            assert!(active);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_id_reuse_cooldown() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let impersonator = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_id_reuse_cooldown(100);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"reused_001"),
+                OptionalValue::None,
+            );
+            sc.revoke_proof(managed_buffer!(b"reused_001"));
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"reused_001"));
+            assert_eq!(sc.purge_revoked(proof_ids), 1);
+        })
+        .assert_ok();
+
+    // Id-ul sters nu poate fi refolosit imediat
+    blockchain.set_block_timestamp(1_099);
+    blockchain
+        .execute_tx(&impersonator, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"reused_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof ID recently deleted");
+
+    blockchain.set_block_timestamp(1_100);
+    blockchain
+        .execute_tx(&impersonator, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"reused_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"reused_001")).into_option().unwrap(),
+                managed_address!(&impersonator)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proof_status_precedence() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let frozen_user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"status_active"),
+                OptionalValue::None,
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"status_sealed"),
+                CertifyOptions {
+                    immutable: true,
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"status_expired"),
+                CertifyOptions {
+                    validity_seconds: Some(50),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"status_revoked"),
+                CertifyOptions {
+                    validity_seconds: Some(50),
+                    ..Default::default()
+                },
+            );
+            sc.revoke_proof(managed_buffer!(b"status_revoked"));
+        })
+        .assert_ok();
+
+    // A doua dovada inghetata expira si ea
+    for (proof_id, validity) in [(b"status_frozen_1", None), (b"status_frozen_2", Some(50u64))] {
+        blockchain
+            .execute_tx(&frozen_user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_with_options(
+                    managed_buffer!(b"PROOF"),
+                    managed_buffer!(proof_id),
+                    CertifyOptions {
+                        validity_seconds: validity,
+                        ..Default::default()
+                    },
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.freeze_all_proofs(managed_address!(&frozen_user), 0, 10);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_050);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let status = |proof_id: &[u8]| sc.get_proof_status(&managed_buffer!(proof_id)).into_option();
+
+            assert_eq!(status(b"status_active"), Some(ProofStatus::Active));
+            assert_eq!(status(b"status_sealed"), Some(ProofStatus::Sealed));
+            assert_eq!(status(b"status_expired"), Some(ProofStatus::Expired));
+            assert_eq!(status(b"status_frozen_1"), Some(ProofStatus::Frozen));
+            assert_eq!(status(b"status_missing"), None);
+
+            // Revoked > Expired > Frozen
+            assert_eq!(status(b"status_revoked"), Some(ProofStatus::Revoked));
+            assert_eq!(status(b"status_frozen_2"), Some(ProofStatus::Expired));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_issuer_reputation_snapshot() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let attester = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"snapshot_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"snapshot_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"snapshot_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Snapshot-ul nu urmeaza reputatia curenta a emitentului
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let issuer_address = managed_address!(&issuer);
+            let first = sc.get_proof(&issuer_address, &managed_buffer!(b"snapshot_001")).into_option().unwrap();
+            let second = sc.get_proof(&issuer_address, &managed_buffer!(b"snapshot_002")).into_option().unwrap();
+
+            assert_eq!(sc.get_issuer_reputation(&issuer_address), 1);
+            assert_eq!(first.issuer_reputation_at_issue, 0);
+            assert_eq!(second.issuer_reputation_at_issue, 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_default_private_proofs() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let reader = blockchain.create_user_account(&rust_biguint!(0));
+    let stranger = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"public_before_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_default_private(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"private_after_001"),
+                OptionalValue::None,
+            );
+            sc.grant_reader(managed_buffer!(b"private_after_001"), managed_address!(&reader));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&stranger, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.grant_reader(managed_buffer!(b"private_after_001"), managed_address!(&stranger));
+        })
+        .assert_user_error("Only proof owner can manage readers");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            let public_id = managed_buffer!(b"public_before_001");
+            let private_id = managed_buffer!(b"private_after_001");
+
+            // Dovezile existente raman publice
+            assert!(!sc.get_proof(&user_address, &public_id).into_option().unwrap().private);
+            assert!(sc.can_read_proof(&public_id, &managed_address!(&stranger)));
+
+            assert!(sc.get_proof(&user_address, &private_id).into_option().unwrap().private);
+            assert!(sc.can_read_proof(&private_id, &user_address));
+            assert!(sc.can_read_proof(&private_id, &managed_address!(&reader)));
+            assert!(!sc.can_read_proof(&private_id, &managed_address!(&stranger)));
+        })
+        .assert_ok();
+}
+
+fn exported_proof(proof_id: &[u8], issuer: &Address, timestamp: u64) -> ProofData<DebugApi> {
+    ProofData {
+        proof_text: managed_buffer!(b"RESTORED_PROOF"),
+        timestamp,
+        proof_id: managed_buffer!(proof_id),
+        metadata: ManagedBuffer::new(),
+        encryption_scheme: ManagedBuffer::new(),
+        issuer: managed_address!(issuer),
+        superseded_by: ManagedBuffer::new(),
+        fee_paid: BigUint::zero(),
+        content_hash: ManagedBuffer::new(),
+        category: managed_buffer!(b"diploma"),
+        expires_at: 0,
+        score_value: 0,
+        score_decimals: 0,
+        compressed: false,
+        compression_algo: ManagedBuffer::new(),
+        flags: FLAGS_LAYOUT_VERSION << 8,
+        fee_token: EgldOrEsdtTokenIdentifier::egld(),
+        epoch: 0,
+        issuer_reputation_at_issue: 0,
+        region: ManagedBuffer::new(),
+        valid_from: 0,
+        document_size: 0,
+        valid_until_epoch: 0,
+        title: ManagedBuffer::new(),
+        checksum: ManagedBuffer::new(),
+    }
+}
+
+#[test]
+fn test_import_proofs_then_lock() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let holder = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_timestamp(5_000);
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.import_proof(managed_address!(&holder), exported_proof(b"imported_001", &holder, 1_000));
+            sc.import_proof(managed_address!(&holder), exported_proof(b"imported_002", &holder, 2_000));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&holder, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.import_proof(managed_address!(&holder), exported_proof(b"imported_003", &holder, 3_000));
+        })
+        .assert_user_error("Endpoint can only be called by owner");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.lock_import();
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.import_proof(managed_address!(&holder), exported_proof(b"imported_003", &holder, 3_000));
+        })
+        .assert_user_error("Import is locked");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let holder_address = managed_address!(&holder);
+            let restored = sc.get_proof(&holder_address, &managed_buffer!(b"imported_001")).into_option().unwrap();
+
+            // Timestamp-ul original este pastrat
+            assert_eq!(restored.timestamp, 1_000);
+            assert_eq!(sc.get_proof_owner(&managed_buffer!(b"imported_002")).into_option().unwrap(), holder_address);
+            assert_eq!(sc.get_user_proof_count(&holder_address), 2);
+            assert_eq!(sc.get_total_proofs(), 2);
+            assert_eq!(sc.get_user_category_count(&holder_address, &managed_buffer!(b"diploma")), 2);
+            assert!(!sc.proof_exists(&managed_buffer!(b"imported_003")));
+            assert!(sc.is_import_locked());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_record_view_count() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let visitor = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"viewed_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    for _ in 0..3 {
+        blockchain
+            .execute_tx(&visitor, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.record_view(managed_buffer!(b"viewed_001"));
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&visitor, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.record_view(managed_buffer!(b"missing_001"));
+        })
+        .assert_user_error("Proof does not exist");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_view_count(&managed_buffer!(b"viewed_001")), 3);
+            assert_eq!(sc.get_view_count(&managed_buffer!(b"missing_001")), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_require_contract_caller() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    // Orice contract poate juca rolul de middleware
+    let middleware_wrapper = blockchain.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        proof_listener_mock::contract_obj,
+        "output/proof-listener-mock.wasm",
+    );
+    let middleware = middleware_wrapper.address_ref().clone();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"eoa_before_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_require_contract_caller(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"eoa_after_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Only contract callers allowed");
+
+    // Restrictia se aplica pe calea comuna, deci si celorlalte endpoint-uri de certificare
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"PROOF"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_user_error("Only contract callers allowed");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"eoa_escrow_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Only contract callers allowed");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&user), OptionalValue::None));
+            assert!(sc.get_effective_issuer_permission(&managed_address!(&middleware), OptionalValue::None));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&middleware, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"middleware_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.proof_exists(&managed_buffer!(b"eoa_before_001")));
+            assert!(!sc.proof_exists(&managed_buffer!(b"eoa_after_001")));
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"middleware_001")).into_option().unwrap(),
+                managed_address!(&middleware)
+            );
+        })
+        .assert_ok();
+
+    // La relay conteaza apelantul tranzactiei, nu utilizatorul care a semnat
+    let signing_key = SigningKey::from_bytes(&[12u8; 32]);
+    let signer = Address::from(signing_key.verifying_key().to_bytes());
+    blockchain.create_user_account_fixed_address(&signer, &rust_biguint!(0));
+
+    let mut message = Vec::new();
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            message = sc
+                .get_relay_message(
+                    &managed_address!(&signer),
+                    &managed_buffer!(b"relayed_mw_001"),
+                    &managed_buffer!(b"RELAYED_PROOF"),
+                    &managed_buffer!(b""),
+                )
+                .to_boxed_bytes()
+                .into_vec();
+        })
+        .assert_ok();
+    let signature = signing_key.sign(&message).to_bytes();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.relay_certify(
+                managed_address!(&signer),
+                managed_buffer!(b"RELAYED_PROOF"),
+                managed_buffer!(b"relayed_mw_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&signature[..]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Only contract callers allowed");
+
+    blockchain
+        .execute_tx(&middleware, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.relay_certify(
+                managed_address!(&signer),
+                managed_buffer!(b"RELAYED_PROOF"),
+                managed_buffer!(b"relayed_mw_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&signature[..]),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"relayed_mw_001")).into_option().unwrap(),
+                managed_address!(&signer)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_rename_proof() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let attester = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            for proof_id in [&b"diplmoa_001"[..], &b"taken_001"[..]] {
+                sc.certify_with_options(
+                    managed_buffer!(b"PROOF"),
+                    managed_buffer!(proof_id),
+                    CertifyOptions {
+                        content_hash: managed_buffer!(proof_id),
+                        category: managed_buffer!(b"diploma"),
+                        ..Default::default()
+                    },
+                );
+            }
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"diplmoa_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.rename_proof(managed_buffer!(b"diplmoa_001"), managed_buffer!(b"taken_001"));
+        })
+        .assert_user_error("Proof ID already exists");
+
+    let tx_result = blockchain.execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+        sc.rename_proof(managed_buffer!(b"diplmoa_001"), managed_buffer!(b"diploma_001"));
+    });
+    tx_result.assert_ok();
+    assert!(tx_result
+        .result_logs
+        .iter()
+        .any(|log| log.topics[0] == b"proofRenamed".to_vec()));
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            let old_id = managed_buffer!(b"diplmoa_001");
+            let new_id = managed_buffer!(b"diploma_001");
+
+            assert!(!sc.proof_exists(&old_id));
+            assert!(sc.get_proof(&user_address, &old_id).into_option().is_none());
+
+            let renamed = sc.get_proof(&user_address, &new_id).into_option().unwrap();
+            assert_eq!(renamed.proof_id, new_id);
+            assert_eq!(sc.get_proof_owner(&new_id).into_option().unwrap(), user_address);
+            assert_eq!(sc.get_attestation_count(&new_id), 1);
+            assert_eq!(
+                sc.find_proof_by_hash(&managed_buffer!(b"diplmoa_001")).into_option().unwrap(),
+                new_id
+            );
+            assert_eq!(sc.get_user_proof_count(&user_address), 2);
+            assert_eq!(sc.get_user_category_count(&user_address, &managed_buffer!(b"diploma")), 2);
+        })
+        .assert_ok();
+
+    // O dovada care inlocuieste alta nu poate fi redenumita: superseded_by ar ramane spre id-ul vechi
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.supersede_proof(managed_buffer!(b"taken_001"), managed_buffer!(b"diploma_001"), true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.rename_proof(managed_buffer!(b"diploma_001"), managed_buffer!(b"diploma_final_001"));
+        })
+        .assert_user_error("Proof is referenced by another proof");
+
+    // Dupa stergerea dovezii inlocuite legatura dispare
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"taken_001"));
+            assert_eq!(sc.purge_revoked(proof_ids), 1);
+            sc.rename_proof(managed_buffer!(b"diploma_001"), managed_buffer!(b"diploma_final_001"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.proof_exists(&managed_buffer!(b"diploma_final_001")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_issuer_whitelist_owner_bypass() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_authorized_issuer(managed_address!(&issuer));
+            sc.set_issuer_whitelist_enabled(true);
+        })
+        .assert_ok();
+
+    // Owner-ul nu este in whitelist dar poate certifica
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"admin_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"issuer_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"user_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Issuer not whitelisted");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.get_effective_issuer_permission(&managed_address!(&owner), OptionalValue::None));
+            assert!(sc.get_effective_issuer_permission(&managed_address!(&issuer), OptionalValue::None));
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&user), OptionalValue::None));
+        })
+        .assert_ok();
+
+    // Pauza blocheaza pe toata lumea, inclusiv owner-ul
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(true, OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&owner), OptionalValue::None));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_issuer_rules_on_every_path() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let approver = blockchain.create_user_account(&rust_biguint!(0));
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_authorized_issuer(managed_address!(&issuer));
+            sc.add_authorized_issuer(managed_address!(&approver));
+            sc.set_issuer_whitelist_enabled(true);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"PROOF"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_user_error("Issuer not whitelisted");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"user_quorum_001"),
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Issuer not whitelisted");
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"issuer_quorum_001"),
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Regulile se reverifica la finalizare: emitentul scos din whitelist nu mai primeste dovada
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_authorized_issuer(managed_address!(&issuer));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&approver, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_proof(managed_buffer!(b"issuer_quorum_001"));
+        })
+        .assert_user_error("Issuer not whitelisted");
+
+    // View-ul raporteaza aceleasi reguli, inclusiv soldul minim
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_certifier_balance(managed_biguint!(100));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&issuer), OptionalValue::None));
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&approver), OptionalValue::None));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proofs_by_region() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"region_eu_001"),
+                CertifyOptions {
+                    region: managed_buffer!(b"EU"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"region_eu_002"),
+                CertifyOptions {
+                    region: managed_buffer!(b"EU"),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"region_us_001"),
+                CertifyOptions {
+                    region: managed_buffer!(b"US"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"region_long_001"),
+                CertifyOptions {
+                    region: managed_buffer!(b"EUROPEAN_UNION_REGION"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Region too long");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let eu = managed_buffer!(b"EU");
+            let us = managed_buffer!(b"US");
+
+            assert_eq!(sc.get_region_count(&eu), 2);
+            assert_eq!(sc.get_region_count(&us), 1);
+            assert_eq!(sc.get_region_count(&managed_buffer!(b"APAC")), 0);
+
+            let eu_page: Vec<ManagedBuffer<DebugApi>> = sc.get_proofs_by_region(&eu, 0, 10).into_iter().collect();
+            assert_eq!(eu_page.len(), 2);
+            assert!(eu_page.contains(&managed_buffer!(b"region_eu_001")));
+            assert!(eu_page.contains(&managed_buffer!(b"region_eu_002")));
+
+            let us_page: Vec<ManagedBuffer<DebugApi>> = sc.get_proofs_by_region(&us, 0, 10).into_iter().collect();
+            assert_eq!(us_page, vec![managed_buffer!(b"region_us_001")]);
+            assert_eq!(sc.get_proofs_by_region(&eu, 1, 10).into_iter().count(), 1);
+
+            let stored = sc.get_proof(&managed_address!(&user), &managed_buffer!(b"region_us_001")).into_option().unwrap();
+            assert_eq!(stored.region, us);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_time_locked_proof() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"future_001"),
+                CertifyOptions {
+                    valid_from: 2_000,
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof_id = managed_buffer!(b"future_001");
+            assert!(sc.proof_exists(&proof_id));
+            assert!(!sc.is_proof_active(&proof_id));
+            assert_eq!(sc.get_proof_status(&proof_id).into_option(), Some(ProofStatus::NotYetValid));
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(2_000);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof_id = managed_buffer!(b"future_001");
+            assert!(sc.is_proof_active(&proof_id));
+            assert_eq!(sc.get_proof_status(&proof_id).into_option(), Some(ProofStatus::Active));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_emit_full_snapshot() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    for i in 0..5 {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"PROOF"),
+                    managed_buffer!(format!("snapshot_{}", i).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    let tx_result = blockchain.execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+        assert!(sc.emit_full_snapshot(0, 3));
+    });
+    tx_result.assert_ok();
+    assert_eq!(
+        tx_result
+            .result_logs
+            .iter()
+            .filter(|log| log.topics[0] == b"proofSnapshot".to_vec())
+            .count(),
+        3
+    );
+
+    // Ultima pagina este incompleta
+    let tx_result = blockchain.execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+        assert!(!sc.emit_full_snapshot(3, 3));
+    });
+    tx_result.assert_ok();
+    assert_eq!(
+        tx_result
+            .result_logs
+            .iter()
+            .filter(|log| log.topics[0] == b"proofSnapshot".to_vec())
+            .count(),
+        2
+    );
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.emit_full_snapshot(0, 3);
+        })
+        .assert_user_error("Endpoint can only be called by owner");
+}
+
+#[test]
+fn test_default_validity() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_default_validity(3_600);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"default_ttl_001"),
+                OptionalValue::None,
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"explicit_ttl_001"),
+                CertifyOptions {
+                    validity_seconds: Some(60),
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"no_expiry_001"),
+                CertifyOptions {
+                    validity_seconds: Some(0),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    // Valorile explicite, inclusiv 0, au prioritate fata de TTL-ul implicit
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            let expires_at = |proof_id: &[u8]| {
+                sc.get_proof(&user_address, &managed_buffer!(proof_id)).into_option().unwrap().expires_at
+            };
+
+            assert_eq!(expires_at(b"default_ttl_001"), 4_600);
+            assert_eq!(expires_at(b"explicit_ttl_001"), 1_060);
+            assert_eq!(expires_at(b"no_expiry_001"), 0);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"overflow_ttl_001"),
+                CertifyOptions {
+                    validity_seconds: Some(u64::MAX),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Proof validity too long");
+}
+
+#[test]
+fn test_gating_nft_required() {
+    const GATING_NFT: &[u8] = b"GATE-123456";
+
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let holder = blockchain.create_user_account(&rust_biguint!(0));
+    let outsider = blockchain.create_user_account(&rust_biguint!(0));
+    blockchain.set_nft_balance(&holder, GATING_NFT, 7, &rust_biguint!(1), &());
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_gating_nft(managed_token_id!(GATING_NFT));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&holder, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"gated_001"),
+                CertifyOptions {
+                    gating_nonce: 7,
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&outsider, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"gated_002"),
+                CertifyOptions {
+                    gating_nonce: 7,
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Gating NFT required");
+
+    blockchain
+        .execute_tx(&outsider, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"gated_003"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Gating NFT required");
+
+    // Celelalte cai de certificare cer acelasi NFT
+    blockchain
+        .execute_tx(&outsider, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"PROOF"), OptionalValue::None, OptionalValue::Some(7));
+        })
+        .assert_user_error("Gating NFT required");
+
+    blockchain
+        .execute_tx(&outsider, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"gated_escrow_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Gating NFT required");
+
+    blockchain
+        .execute_tx(&outsider, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"gated_quorum_001"),
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Gating NFT required");
+
+    blockchain
+        .execute_tx(&holder, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"PROOF"), OptionalValue::None, OptionalValue::Some(7));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.get_effective_issuer_permission(&managed_address!(&holder), OptionalValue::Some(7)));
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&holder), OptionalValue::None));
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&outsider), OptionalValue::Some(7)));
+        })
+        .assert_ok();
+
+    // Fara gating oricine poate certifica din nou
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.clear_gating_nft();
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&outsider, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"gated_004"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proof_count_in_range() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    for (i, timestamp) in [100u64, 200, 250, 400].iter().enumerate() {
+        blockchain.set_block_timestamp(*timestamp);
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"PROOF"),
+                    managed_buffer!(format!("bucket_{}", i).as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    // Inceputul ferestrei este inclus, sfarsitul nu
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            assert_eq!(sc.get_proof_count_in_range(&user_address, 0, 1_000), 4);
+            assert_eq!(sc.get_proof_count_in_range(&user_address, 200, 300), 2);
+            assert_eq!(sc.get_proof_count_in_range(&user_address, 100, 200), 1);
+            assert_eq!(sc.get_proof_count_in_range(&user_address, 401, 1_000), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_certification_reward_pool() {
+    const REWARD_TOKEN: &[u8] = b"RWD-123456";
+
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    blockchain.set_esdt_balance(&owner, REWARD_TOKEN, &rust_biguint!(250));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_reward_config(managed_token_id!(REWARD_TOKEN), managed_biguint!(100));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_esdt_transfer(&owner, &contract_wrapper, REWARD_TOKEN, 0, &rust_biguint!(250), |sc| {
+            sc.fund_reward_pool();
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"rewarded_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+    blockchain.check_esdt_balance(&user, REWARD_TOKEN, &rust_biguint!(100));
+
+    // Recompensa se plateste si pe celelalte cai de certificare
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"AUTO_PROOF"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_ok();
+    blockchain.check_esdt_balance(&user, REWARD_TOKEN, &rust_biguint!(200));
+
+    // Pool-ul ramas (50) nu acopera recompensa: certificarea reuseste fara plata
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"unrewarded_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+    blockchain.check_esdt_balance(&user, REWARD_TOKEN, &rust_biguint!(200));
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_reward_pool(), managed_biguint!(50));
+            assert!(sc.proof_exists(&managed_buffer!(b"unrewarded_001")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_get_all_proof_owners() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let partner_a = blockchain.create_user_account(&rust_biguint!(0));
+    let partner_b = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"shared_001"),
+                OptionalValue::None,
+            );
+            sc.add_co_owner(managed_buffer!(b"shared_001"), managed_address!(&partner_a));
+            sc.add_co_owner(managed_buffer!(b"shared_001"), managed_address!(&partner_b));
+            // Adaugarea repetata nu creeaza duplicate
+            sc.add_co_owner(managed_buffer!(b"shared_001"), managed_address!(&partner_a));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&partner_a, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_co_owner(managed_buffer!(b"shared_001"), managed_address!(&partner_a));
+        })
+        .assert_user_error("Only proof owner can manage co-owners");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let owners: Vec<ManagedAddress<DebugApi>> =
+                sc.get_all_proof_owners(&managed_buffer!(b"shared_001")).into_iter().collect();
+
+            assert_eq!(owners.len(), 3);
+            assert_eq!(owners[0], managed_address!(&user));
+            assert!(owners.contains(&managed_address!(&partner_a)));
+            assert!(owners.contains(&managed_address!(&partner_b)));
+
+            assert_eq!(sc.get_all_proof_owners(&managed_buffer!(b"missing_001")).into_iter().count(), 0);
+        })
+        .assert_ok();
+
+    // Co-ownerii pot edita dovada, dar nu o pot transfera
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.can_edit_proof(&managed_buffer!(b"shared_001"), &managed_address!(&partner_a)));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&partner_a, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_title(managed_buffer!(b"shared_001"), managed_buffer!(b"Shared title"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&partner_a, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.transfer_proof_ownership(managed_buffer!(b"shared_001"), managed_address!(&partner_a));
+        })
+        .assert_user_error("Only proof owner can transfer");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_co_owner(managed_buffer!(b"shared_001"), managed_address!(&partner_a));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"shared_001"), &managed_address!(&partner_a)));
+            assert_eq!(
+                sc.get_proof(&managed_address!(&user), &managed_buffer!(b"shared_001")).into_option().unwrap().title,
+                managed_buffer!(b"Shared title")
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&partner_a, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_title(managed_buffer!(b"shared_001"), managed_buffer!(b"Other title"));
+        })
+        .assert_user_error("Only proof owner or editor can update");
+}
+
+#[test]
+fn test_pause_reason() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(true, OptionalValue::Some(managed_buffer!(b"Incident 42: key rotation")));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_service_status(), 1);
+            assert_eq!(sc.get_pause_reason(), managed_buffer!(b"Incident 42: key rotation"));
+        })
+        .assert_ok();
+
+    // Reluarea sterge motivul chiar daca se trimite unul
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(false, OptionalValue::Some(managed_buffer!(b"ignored")));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_service_status(), 0);
+            assert!(sc.get_pause_reason().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_pause_blocks_state_changes() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let other = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"paused_state_001"),
+                OptionalValue::None,
+            );
+            sc.offer_transfer(managed_buffer!(b"paused_state_001"), managed_address!(&other));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_paused(true, OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_proof(managed_buffer!(b"paused_state_001"));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.grant_reader(managed_buffer!(b"paused_state_001"), managed_address!(&other));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_editor(managed_buffer!(b"paused_state_001"), managed_address!(&other));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_co_owner(managed_buffer!(b"paused_state_001"), managed_address!(&other));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.record_view(managed_buffer!(b"paused_state_001"));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.bookmark_proof(managed_buffer!(b"paused_state_001"));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.prune_expired_attestations(managed_buffer!(b"paused_state_001"));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.cancel_transfer(managed_buffer!(b"paused_state_001"));
+        })
+        .assert_user_error("Contract is paused");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_proof_status(&managed_buffer!(b"paused_state_001")).into_option(),
+                Some(ProofStatus::Active)
+            );
+            assert_eq!(sc.get_view_count(&managed_buffer!(b"paused_state_001")), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_total_document_bytes() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let other = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"sized_001"),
+                CertifyOptions {
+                    document_size: 1_024,
+                    ..Default::default()
+                },
+            );
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"sized_002"),
+                CertifyOptions {
+                    document_size: 4_096,
+                    ..Default::default()
+                },
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"unsized_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            assert_eq!(sc.get_total_document_bytes(&user_address), 5_120);
+            assert_eq!(sc.get_total_document_bytes(&managed_address!(&other)), 0);
+
+            let stored = sc.get_proof(&user_address, &managed_buffer!(b"sized_002")).into_option().unwrap();
+            assert_eq!(stored.document_size, 4_096);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_transfer_selected_proofs() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let recipient = blockchain.create_user_account(&rust_biguint!(0));
+    let stranger = blockchain.create_user_account(&rust_biguint!(0));
+
+    for (caller, proof_id) in [
+        (&user, &b"selected_001"[..]),
+        (&user, &b"selected_002"[..]),
+        (&user, &b"kept_001"[..]),
+        (&stranger, &b"foreign_001"[..]),
+    ] {
+        blockchain
+            .execute_tx(caller, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"PROOF"),
+                    managed_buffer!(proof_id),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"selected_001"));
+            sc.transfer_proofs(proof_ids, managed_address!(&user));
+        })
+        .assert_user_error("Cannot transfer to self");
+
+    // Dovada altui utilizator este ignorata
+    let tx_result = blockchain.execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+        let mut proof_ids = MultiValueEncoded::new();
+        proof_ids.push(managed_buffer!(b"selected_001"));
+        proof_ids.push(managed_buffer!(b"selected_002"));
+        proof_ids.push(managed_buffer!(b"foreign_001"));
+        assert_eq!(sc.transfer_proofs(proof_ids, managed_address!(&recipient)), 2);
+    });
+    tx_result.assert_ok();
+    assert_eq!(
+        tx_result
+            .result_logs
+            .iter()
+            .filter(|log| log.topics[0] == b"proofTransferred".to_vec())
+            .count(),
+        2
+    );
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&user)), 1);
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&recipient)), 2);
+            assert_eq!(sc.get_user_proof_count(&managed_address!(&stranger)), 1);
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"selected_002")).into_option().unwrap(),
+                managed_address!(&recipient)
+            );
+            assert_eq!(
+                sc.get_proof_owner(&managed_buffer!(b"foreign_001")).into_option().unwrap(),
+                managed_address!(&stranger)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_attestation_expiry() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let temporary = blockchain.create_user_account(&rust_biguint!(0));
+    let permanent = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"endorsed_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&temporary, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"endorsed_001"), OptionalValue::Some(u64::MAX));
+        })
+        .assert_user_error("Attestation validity too long");
+
+    blockchain
+        .execute_tx(&temporary, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"endorsed_001"), OptionalValue::Some(100));
+        })
+        .assert_ok();
+    blockchain
+        .execute_tx(&permanent, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"endorsed_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_099);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_attestation_count(&managed_buffer!(b"endorsed_001")), 2);
+        })
+        .assert_ok();
+
+    // Atestarea temporara nu mai conteaza, chiar inainte de prune
+    blockchain.set_block_timestamp(1_100);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_attestation_count(&managed_buffer!(b"endorsed_001")), 1);
+            assert_eq!(sc.get_weighted_attestation_score(&managed_buffer!(b"endorsed_001")), 1);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            assert_eq!(sc.prune_expired_attestations(managed_buffer!(b"endorsed_001")), 1);
+            assert_eq!(sc.prune_expired_attestations(managed_buffer!(b"endorsed_001")), 0);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_attestation_count(&managed_buffer!(b"endorsed_001")), 1);
+            assert_eq!(sc.get_attested_proofs(&managed_address!(&temporary), 0, 10).into_iter().count(), 0);
+            assert_eq!(sc.get_attested_proofs(&managed_address!(&permanent), 0, 10).into_iter().count(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_banned_substrings() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_banned_substring(managed_buffer!(b"SCAM"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"DIPLOMA_COMPUTER_SCIENCE"),
+                managed_buffer!(b"clean_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"FREE_SCAM_AIRDROP"),
+                managed_buffer!(b"banned_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof text contains banned content");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(managed_buffer!(b"clean_001"), managed_buffer!(b"NOW_A_SCAM"), OptionalValue::None);
+        })
+        .assert_user_error("Proof text contains banned content");
+
+    // Marcajul de compresie nu ocoleste scanarea
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"\x28\xb5\x2f\xfdFREE_SCAM_AIRDROP"),
+                managed_buffer!(b"compressed_banned_001"),
+                CertifyOptions {
+                    compression_algo: managed_buffer!(b"zstd"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Proof text contains banned content");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_banned_substring(managed_buffer!(b"SCAM"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"FREE_SCAM_AIRDROP"),
+                managed_buffer!(b"banned_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proof_id_availability() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let university = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_id_reuse_cooldown(100);
+            sc.reserve_prefix(managed_buffer!(b"UNIV-"), managed_address!(&university));
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_000);
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"existing_001"),
+                OptionalValue::None,
+            );
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"deleted_001"),
+                OptionalValue::None,
+            );
+            sc.revoke_proof(managed_buffer!(b"deleted_001"));
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"deleted_001"));
+            sc.purge_revoked(proof_ids);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"UNIV-2024-001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof ID prefix is reserved");
+
+    // Propunerile multisig respecta aceleasi reguli ca certificarea directa
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"UNIV-2024-001"),
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof ID prefix is reserved");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_proof(
+                managed_buffer!(b"deleted_001"),
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b""),
+                1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Proof ID recently deleted");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let user_address = managed_address!(&user);
+            let university_address = managed_address!(&university);
+
+            assert!(sc.is_proof_id_available(&managed_buffer!(b"free_001"), &user_address));
+            assert!(!sc.is_proof_id_available(&managed_buffer!(b"existing_001"), &user_address));
+            assert!(!sc.is_proof_id_available(&managed_buffer!(b"deleted_001"), &user_address));
+
+            // Prefixul rezervat este liber doar pentru emitentul caruia ii apartine
+            assert!(!sc.is_proof_id_available(&managed_buffer!(b"UNIV-2024-001"), &user_address));
+            assert!(sc.is_proof_id_available(&managed_buffer!(b"UNIV-2024-001"), &university_address));
+        })
+        .assert_ok();
+
+    blockchain.set_block_timestamp(1_100);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.is_proof_id_available(&managed_buffer!(b"deleted_001"), &managed_address!(&user)));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&university, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"UNIV-2024-001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_proof_integrity_checksum() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"checked_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.verify_proof_integrity(&managed_buffer!(b"checked_001")));
+            assert!(!sc.verify_proof_integrity(&managed_buffer!(b"missing_001")));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(managed_buffer!(b"checked_001"), managed_buffer!(b"PROOF_V2"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.verify_proof_integrity(&managed_buffer!(b"checked_001")));
+        })
+        .assert_ok();
+
+    // Scriere directa in storage, ocolind save_proof: simuleaza o corupere
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.user_proofs(&managed_address!(&user), &managed_buffer!(b"checked_001"))
+                .update(|proof_data| proof_data.proof_text = managed_buffer!(b"TAMPERED"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.verify_proof_integrity(&managed_buffer!(b"checked_001")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_fee_split_between_beneficiaries() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(1000));
+    let treasury = blockchain.create_user_account(&rust_biguint!(0));
+    let charity = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut beneficiaries = MultiValueEncoded::new();
+            beneficiaries.push((managed_address!(&treasury), 7_000u32).into());
+            beneficiaries.push((managed_address!(&charity), 2_000u32).into());
+            sc.set_fee_beneficiaries(beneficiaries);
+        })
+        .assert_user_error("Beneficiary shares must sum to 10000 bps");
+
+    // O cota uriasa ar face wrap la insumare fara verificarea per intrare
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut beneficiaries = MultiValueEncoded::new();
+            beneficiaries.push((managed_address!(&treasury), 10_000u32).into());
+            beneficiaries.push((managed_address!(&charity), u32::MAX - 9_999).into());
+            sc.set_fee_beneficiaries(beneficiaries);
+        })
+        .assert_user_error("Beneficiary share exceeds 10000 bps");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut beneficiaries = MultiValueEncoded::new();
+            beneficiaries.push((managed_address!(&treasury), 7_000u32).into());
+            beneficiaries.push((managed_address!(&charity), 3_000u32).into());
+            sc.set_fee_beneficiaries(beneficiaries);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(200), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PAID_PROOF"),
+                managed_buffer!(b"split_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // Cu beneficiari configurati, retragerea manuala este blocata
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.withdraw_fees_to(managed_address!(&owner), EgldOrEsdtTokenIdentifier::egld(), managed_biguint!(10));
+        })
+        .assert_user_error("Fees are split between beneficiaries");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.distribute_fees(EgldOrEsdtTokenIdentifier::egld());
+        })
+        .assert_ok();
+
+    blockchain.check_egld_balance(&treasury, &rust_biguint!(140));
+    blockchain.check_egld_balance(&charity, &rust_biguint!(60));
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_fee_reserve(), managed_biguint!(0));
+        })
+        .assert_ok();
+}
+
+
+#[test]
+fn test_issuers_paged() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let alice = blockchain.create_user_account(&rust_biguint!(0));
+    let bob = blockchain.create_user_account(&rust_biguint!(0));
+    let carol = blockchain.create_user_account(&rust_biguint!(0));
+
+    for (issuer, count, prefix) in [(&alice, 3u32, "a"), (&bob, 1u32, "b"), (&carol, 2u32, "c")] {
+        for i in 0..count {
+            let proof_id = format!("{}_issuer_{}", prefix, i);
+            blockchain
+                .execute_tx(issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+                    sc.certify_action(
+                        managed_buffer!(b"ISSUED"),
+                        managed_buffer!(proof_id.as_bytes()),
+                        OptionalValue::None,
+                    );
+                })
+                .assert_ok();
+        }
+    }
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let first_page: Vec<(ManagedAddress<DebugApi>, u64)> = sc
+                .get_issuers_paged(0, 2)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(first_page.len(), 2);
+            assert_eq!(first_page[0], (managed_address!(&alice), 3));
+            assert_eq!(first_page[1], (managed_address!(&bob), 1));
+
+            let second_page: Vec<(ManagedAddress<DebugApi>, u64)> = sc
+                .get_issuers_paged(2, 2)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(second_page.len(), 1);
+            assert_eq!(second_page[0], (managed_address!(&carol), 2));
+
+            assert_eq!(sc.get_issuers_paged(3, 2).into_iter().count(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_epoch_based_expiry() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    blockchain.set_block_epoch(10);
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"EPOCH_PROOF"),
+                managed_buffer!(b"epoch_both"),
+                CertifyOptions {
+                    validity_seconds: Some(3_600),
+                    valid_until_epoch: 12,
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Validity can be set in seconds or epochs, not both");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"EPOCH_PROOF"),
+                managed_buffer!(b"epoch_past"),
+                CertifyOptions {
+                    valid_until_epoch: 10,
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Expiry epoch must be in the future");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"EPOCH_PROOF"),
+                managed_buffer!(b"epoch_001"),
+                CertifyOptions {
+                    valid_until_epoch: 12,
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    // Timestamp-ul nu influenteaza o dovada cu expirare pe epoci
+    blockchain.set_block_epoch(11);
+    blockchain.set_block_timestamp(1_000_000);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let stored = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"epoch_001"))
+                .into_option()
+                .unwrap();
+            assert_eq!(stored.valid_until_epoch, 12);
+            assert_eq!(stored.expires_at, 0);
+            assert_eq!(
+                sc.get_proof_status(&managed_buffer!(b"epoch_001")).into_option(),
+                Some(ProofStatus::Active)
+            );
+        })
+        .assert_ok();
+
+    blockchain.set_block_epoch(12);
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_proof_status(&managed_buffer!(b"epoch_001")).into_option(),
+                Some(ProofStatus::Expired)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_merge_proofs() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let other = blockchain.create_user_account(&rust_biguint!(0));
+    let attester_a = blockchain.create_user_account(&rust_biguint!(0));
+    let attester_b = blockchain.create_user_account(&rust_biguint!(0));
+
+    for proof_id in ["canonical_001", "duplicate_001"] {
+        blockchain
+            .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.certify_action(
+                    managed_buffer!(b"DIPLOMA"),
+                    managed_buffer!(proof_id.as_bytes()),
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    // attester_a a atestat ambele dovezi, attester_b doar duplicatul
+    for (attester, proof_id) in [
+        (&attester_a, "canonical_001"),
+        (&attester_a, "duplicate_001"),
+        (&attester_b, "duplicate_001"),
+    ] {
+        blockchain
+            .execute_tx(attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+                sc.attest_proof(managed_buffer!(proof_id.as_bytes()), OptionalValue::None);
+            })
+            .assert_ok();
+    }
+
+    blockchain
+        .execute_tx(&other, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.merge_proofs(managed_buffer!(b"canonical_001"), managed_buffer!(b"duplicate_001"));
+        })
+        .assert_user_error("Only owner of both proofs can merge");
+
+    let tx_result = blockchain.execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+        sc.merge_proofs(managed_buffer!(b"canonical_001"), managed_buffer!(b"duplicate_001"));
+    });
+    tx_result.assert_ok();
+    assert!(tx_result
+        .result_logs
+        .iter()
+        .any(|log| log.topics[0] == b"proofMerged".to_vec()));
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(
+                sc.resolve_proof_id(&managed_buffer!(b"duplicate_001")),
+                managed_buffer!(b"canonical_001")
+            );
+            assert_eq!(
+                sc.resolve_proof_id(&managed_buffer!(b"canonical_001")),
+                managed_buffer!(b"canonical_001")
+            );
+            assert_eq!(sc.get_attestation_count(&managed_buffer!(b"canonical_001")), 2);
+            assert_eq!(sc.get_attestation_count(&managed_buffer!(b"duplicate_001")), 0);
+
+            // Duplicatul iese din clasament, atestarile nu se numara de doua ori
+            let top: Vec<(ManagedBuffer<DebugApi>, u32)> = sc
+                .get_top_proofs(10)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+            assert_eq!(top, vec![(managed_buffer!(b"canonical_001"), 2)]);
+        })
+        .assert_ok();
+
+    // Duplicatul este doar o redirectionare
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.merge_proofs(managed_buffer!(b"duplicate_001"), managed_buffer!(b"canonical_001"));
+        })
+        .assert_user_error("Kept proof is already merged");
+    blockchain
+        .execute_tx(&other, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"duplicate_001"), OptionalValue::None);
+        })
+        .assert_user_error("Proof was merged");
+}
+
+#[test]
+fn test_prerequisite_category_required() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let verified = blockchain.create_user_account(&rust_biguint!(0));
+    let unverified = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&verified, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"identity_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"identity"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_required_prerequisite_category(managed_buffer!(b"identity"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&verified, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"license_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&unverified, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"license_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Prerequisite proof required");
+
+    // Si celelalte cai de certificare cer dovada prealabila
+    blockchain
+        .execute_tx(&unverified, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_auto(managed_buffer!(b"KYC"), OptionalValue::None, OptionalValue::None);
+        })
+        .assert_user_error("Prerequisite proof required");
+
+    blockchain
+        .execute_tx(&unverified, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.escrow_proof(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"license_escrow_001"),
+                managed_buffer!(b""),
+                managed_buffer!(&[7u8; 32]),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Prerequisite proof required");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.get_effective_issuer_permission(&managed_address!(&verified), OptionalValue::None));
+            assert!(!sc.get_effective_issuer_permission(&managed_address!(&unverified), OptionalValue::None));
+        })
+        .assert_ok();
+
+    // Configuratia goala dezactiveaza verificarea
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_required_prerequisite_category(ManagedBuffer::new());
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&unverified, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"license_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_prerequisite_category_bootstrap() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    // Regula este activa inainte sa existe vreo dovada in categorie
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_required_prerequisite_category(managed_buffer!(b"identity"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"bootstrap_license_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Prerequisite proof required");
+
+    // Dovada din categoria ceruta nu are nevoie de una prealabila
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"bootstrap_identity_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"identity"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"KYC"),
+                managed_buffer!(b"bootstrap_license_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(sc.get_effective_issuer_permission(&managed_address!(&user), OptionalValue::None));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_check_existence() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"exists_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"exists_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"exists_001"));
+            proof_ids.push(managed_buffer!(b"missing_001"));
+            proof_ids.push(managed_buffer!(b"exists_002"));
+            proof_ids.push(managed_buffer!(b"missing_002"));
+
+            let results: Vec<(ManagedBuffer<DebugApi>, bool)> = sc
+                .check_existence(proof_ids)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+
+            assert_eq!(
+                results,
+                vec![
+                    (managed_buffer!(b"exists_001"), true),
+                    (managed_buffer!(b"missing_001"), false),
+                    (managed_buffer!(b"exists_002"), true),
+                    (managed_buffer!(b"missing_002"), false),
+                ]
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_escrow_and_release_proof() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let issuer = blockchain.create_user_account(&rust_biguint!(0));
+    let releaser = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&issuer, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let condition_hash = sc.crypto().sha256(&managed_buffer!(b"milestone-1")).as_managed_buffer().clone();
+            sc.escrow_proof(
+                managed_buffer!(b"MILESTONE_CREDENTIAL"),
+                managed_buffer!(b"escrow_001"),
+                managed_buffer!(b""),
+                condition_hash,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"escrow_001"));
+            let (_, valid) = sc.verify_batch(proof_ids).into_iter().next().unwrap().into_tuple();
+            assert!(!valid);
+            assert_eq!(
+                sc.get_proof_status(&managed_buffer!(b"escrow_001")).into_option(),
+                Some(ProofStatus::Escrowed)
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&releaser, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.release_proof(managed_buffer!(b"escrow_001"), managed_buffer!(b"milestone-2"));
+        })
+        .assert_user_error("Release condition not met");
+
+    blockchain
+        .execute_tx(&releaser, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.release_proof(managed_buffer!(b"escrow_001"), managed_buffer!(b"milestone-1"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let mut proof_ids = MultiValueEncoded::new();
+            proof_ids.push(managed_buffer!(b"escrow_001"));
+            let (_, valid) = sc.verify_batch(proof_ids).into_iter().next().unwrap().into_tuple();
+            assert!(valid);
+            assert_eq!(
+                sc.get_proof_status(&managed_buffer!(b"escrow_001")).into_option(),
+                Some(ProofStatus::Active)
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&releaser, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.release_proof(managed_buffer!(b"escrow_001"), managed_buffer!(b"milestone-1"));
+        })
+        .assert_user_error("Proof is not in escrow");
+}
+
+#[test]
+fn test_proof_title() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"Bachelor of Science in Computer Science, University of Bucharest, 2024"),
+                managed_buffer!(b"titled_001"),
+                CertifyOptions {
+                    title: managed_buffer!(b"BSc Computer Science"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"Bachelor of Science in Computer Science, University of Bucharest, 2024"),
+                managed_buffer!(b"titled_002"),
+                CertifyOptions {
+                    title: managed_buffer!(&[b'x'; 101][..]),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Title too long");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let stored = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"titled_001"))
+                .into_option()
+                .unwrap();
+            assert_eq!(stored.title, managed_buffer!(b"BSc Computer Science"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_title(managed_buffer!(b"titled_001"), managed_buffer!(&[b'x'; 101][..]));
+        })
+        .assert_user_error("Title too long");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_title(managed_buffer!(b"titled_001"), managed_buffer!(b"BSc CS (honours)"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let stored = sc
+                .get_proof(&managed_address!(&user), &managed_buffer!(b"titled_001"))
+                .into_option()
+                .unwrap();
+            assert_eq!(stored.title, managed_buffer!(b"BSc CS (honours)"));
+            assert_eq!(
+                stored.proof_text,
+                managed_buffer!(b"Bachelor of Science in Computer Science, University of Bucharest, 2024")
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_event_schema_version_topic() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_event_schema_version(2);
+        })
+        .assert_user_error("Endpoint can only be called by owner");
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_event_schema_version(2);
+        })
+        .assert_ok();
+
+    let tx_result = blockchain.execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+        sc.certify_action(
+            managed_buffer!(b"PROOF"),
+            managed_buffer!(b"versioned_001"),
+            OptionalValue::None,
+        );
+    });
+    tx_result.assert_ok();
+
+    let log = tx_result
+        .result_logs
+        .iter()
+        .find(|log| log.topics[0] == b"proofCertified".to_vec())
+        .expect("proofCertified event missing");
+    // Topic-urile sunt top-encoded: u32 = 2 devine un singur byte
+    assert_eq!(log.topics[1], vec![2u8]);
+
+    let tx_result = blockchain.execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+        sc.revoke_proof(managed_buffer!(b"versioned_001"));
+    });
+    tx_result.assert_ok();
+
+    let log = tx_result
+        .result_logs
+        .iter()
+        .find(|log| log.topics[0] == b"proofRevoked".to_vec())
+        .expect("proofRevoked event missing");
+    assert_eq!(log.topics[1], vec![2u8]);
+}
+
+#[test]
+fn test_category_fee_override() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(1_000));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_certification_fee(managed_biguint!(100));
+            sc.set_category_fee(managed_buffer!(b"license"), managed_biguint!(500));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert_eq!(sc.get_category_fee(&managed_buffer!(b"license")), managed_biguint!(500));
+            assert_eq!(sc.get_category_fee(&managed_buffer!(b"diploma")), managed_biguint!(100));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"license_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"license"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_user_error("Insufficient certification fee");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(500), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"license_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"license"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    // Categoriile fara suprascriere folosesc taxa globala
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(100), |sc| {
+            sc.certify_with_options(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"diploma_001"),
+                CertifyOptions {
+                    category: managed_buffer!(b"diploma"),
+                    ..Default::default()
+                },
+            );
+        })
+        .assert_ok();
+
+    blockchain.check_egld_balance(&user, &rust_biguint!(400));
+}
+
+#[test]
+fn test_get_proof_context() {
+    let (mut blockchain, _owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+    let attester = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"context_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&attester, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.attest_proof(managed_buffer!(b"context_001"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            let proof_id = managed_buffer!(b"context_001");
+            let (proof_view, owner, status, attestations) =
+                sc.get_proof_context(&proof_id).into_option().unwrap().into_tuple();
+
+            assert_eq!(
+                proof_view,
+                sc.get_proof(&managed_address!(&user), &proof_id).into_option().unwrap()
+            );
+            assert_eq!(Some(owner), sc.get_proof_owner(&proof_id).into_option());
+            assert_eq!(Some(status), sc.get_proof_status(&proof_id).into_option());
+            assert_eq!(attestations, sc.get_attestation_count(&proof_id));
+            assert_eq!(attestations, 1);
+
+            assert!(sc.get_proof_context(&managed_buffer!(b"context_missing")).is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_append_only_mode() {
+    let (mut blockchain, owner, contract_wrapper) = setup();
+    let user = blockchain.create_user_account(&rust_biguint!(0));
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_append_only(true);
+        })
+        .assert_ok();
+
+    // Certificarea ramane permisa
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"append_001"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.certify_action(
+                managed_buffer!(b"PROOF"),
+                managed_buffer!(b"append_002"),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(managed_buffer!(b"append_001"), managed_buffer!(b"EDITED"), OptionalValue::None);
+        })
+        .assert_user_error("Contract is append-only");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_title(managed_buffer!(b"append_001"), managed_buffer!(b"Edited title"));
+        })
+        .assert_user_error("Contract is append-only");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut updates = MultiValueEncoded::new();
+            updates.push((managed_buffer!(b"append_001"), managed_buffer!(b"edited")).into());
+            sc.batch_update_metadata(updates);
+        })
+        .assert_user_error("Contract is append-only");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.rename_proof(managed_buffer!(b"append_001"), managed_buffer!(b"append_renamed"));
+        })
+        .assert_user_error("Contract is append-only");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.supersede_proof(managed_buffer!(b"append_001"), managed_buffer!(b"append_002"), false);
+        })
+        .assert_user_error("Contract is append-only");
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.merge_proofs(managed_buffer!(b"append_001"), managed_buffer!(b"append_002"));
+        })
+        .assert_user_error("Contract is append-only");
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.can_edit_proof(&managed_buffer!(b"append_001"), &managed_address!(&user)));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.revoke_proof(managed_buffer!(b"append_002"));
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&owner, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_append_only(false);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_tx(&user, &contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.update_proof(managed_buffer!(b"append_001"), managed_buffer!(b"EDITED"), OptionalValue::None);
+        })
+        .assert_ok();
+
+    blockchain
+        .execute_query(&contract_wrapper, |sc| {
+            assert!(!sc.is_append_only());
+            assert!(sc.can_edit_proof(&managed_buffer!(b"append_001"), &managed_address!(&user)));
+        })
+        .assert_ok();
+}